@@ -1,9 +1,9 @@
 // This module contains all stuff related to the generation of the contract bindings
 
 use crate::types::{FunctionInfo, ImplInfo, Mutability, Payable, StructInfo};
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
-use syn::{parse_quote, token::Comma};
+use syn::{parse_quote, punctuated::Punctuated, token::Comma, FnArg, Type};
 
 // This function is used to generate a new struct with the name of the contract struct + Test
 // ex. contract struct name is OracleConnector then the binding struct will have the name OracleConnectorTest.
@@ -80,56 +80,195 @@ fn json_serialize(func_info: &FunctionInfo) -> TokenStream {
     quote! {
         let serialized_args = near_sdk::serde_json::json!({#args}).to_string();
         print_log!("Called {}, params: {}", #func_name.green().bold(), serialized_args.cyan());
-        let args = serialized_args.into_bytes();
+        let args = serialized_args.clone().into_bytes();
     }
 }
 
 // This function is used to generate a binding for the Immutable(view) functions
+// Additionally the binding with the _at suffix is generated, which executes the view call
+// at the particular block, specified by the BlockReference
 pub(crate) fn generate_view_function(func_info: &FunctionInfo) -> TokenStream {
     let name_str = func_info.function_name.to_string();
-    generate_function(
+    let mut output = generate_function(
         func_info,
+        func_info.function_name.clone(),
         quote! {integration_tests_toolset::pending_tx::immutable_tx::ImmutablePendingTx::new(&self.contract, String::from(#name_str), args).view().await?;},
         quote! {integration_tests_toolset::tx_result::ViewResult},
         quote! {},
         quote! {use integration_tests_toolset::pending_tx::view::View;},
-    )
+        quote! {(None, None)},
+    );
+
+    output.extend(generate_function(
+        func_info,
+        format_ident!("{}_at", func_info.function_name),
+        quote! {integration_tests_toolset::pending_tx::immutable_tx::ImmutablePendingTx::new(&self.contract, String::from(#name_str), args).at(block_reference).view().await?;},
+        quote! {integration_tests_toolset::tx_result::ViewResult},
+        quote! {block_reference: integration_tests_toolset::pending_tx::block_reference::BlockReference},
+        quote! {use integration_tests_toolset::pending_tx::view::View;},
+        quote! {(None, None)},
+    ));
+
+    output
 }
 
 // This function is used to generate a binding for the Mutable(call) functions
+// Additionally the binding with the _submit suffix is generated, which submits the transaction
+// without waiting for its execution
 pub(crate) fn generate_non_payable_call_function(func_info: &FunctionInfo) -> TokenStream {
     let name_str = func_info.function_name.to_string();
-    generate_function(
+    let mut output = generate_function(
         func_info,
-        quote! {integration_tests_toolset::pending_tx::mutable_tx::MutablePendingTx::new(&self.contract, String::from(#name_str), args).call(caller).await?;},
+        func_info.function_name.clone(),
+        quote! {integration_tests_toolset::pending_tx::mutable_tx::MutablePendingTx::new(&self.contract, String::from(#name_str), args).call(caller.signer()).await?;},
         quote! {integration_tests_toolset::tx_result::CallResult},
-        quote! {caller: &workspaces::Account},
-        quote! {use integration_tests_toolset::pending_tx::call::Call;},
-    )
+        quote! {caller: &impl integration_tests_toolset::pending_tx::signer::TxSigner},
+        quote! {use integration_tests_toolset::pending_tx::{call::Call, signer::TxSigner};},
+        quote! {(Some(0), Some(caller.signer().id().clone()))},
+    );
+
+    output.extend(generate_submit_function(
+        func_info,
+        quote! {integration_tests_toolset::pending_tx::mutable_tx::MutablePendingTx::new(&self.contract, String::from(#name_str), args).submit(caller.signer()).await?;},
+        quote! {caller: &impl integration_tests_toolset::pending_tx::signer::TxSigner},
+        quote! {0},
+    ));
+
+    output
 }
 
 // This function is used to generate a binding for the Mutable(call) and payable function functions
+// Additionally the binding with the _submit suffix is generated, which submits the transaction
+// without waiting for its execution
 pub(crate) fn generate_payable_call_function(func_info: &FunctionInfo) -> TokenStream {
     let name_str = func_info.function_name.to_string();
-    generate_function(
+    let mut output = generate_function(
         func_info,
-        quote! {integration_tests_toolset::pending_tx::payable_tx::PayablePendingTx::new(&self.contract, String::from(#name_str), args, attached_deposit).call(caller).await?;},
+        func_info.function_name.clone(),
+        quote! {integration_tests_toolset::pending_tx::payable_tx::PayablePendingTx::new(&self.contract, String::from(#name_str), args, attached_deposit).call(caller.signer()).await?;},
         quote! {integration_tests_toolset::tx_result::CallResult},
-        quote! {caller: &workspaces::Account, attached_deposit: u128},
-        quote! {use integration_tests_toolset::pending_tx::call::Call;},
-    )
+        quote! {caller: &impl integration_tests_toolset::pending_tx::signer::TxSigner, attached_deposit: u128},
+        quote! {use integration_tests_toolset::pending_tx::{call::Call, signer::TxSigner};},
+        quote! {(Some(attached_deposit), Some(caller.signer().id().clone()))},
+    );
+
+    output.extend(generate_submit_function(
+        func_info,
+        quote! {integration_tests_toolset::pending_tx::payable_tx::PayablePendingTx::new(&self.contract, String::from(#name_str), args, attached_deposit).submit(caller.signer()).await?;},
+        quote! {caller: &impl integration_tests_toolset::pending_tx::signer::TxSigner, attached_deposit: u128},
+        quote! {attached_deposit},
+    ));
+
+    output
 }
 
+// The `call_info` is the expression of the attached deposit and the caller of the function, e.g. `(None, None)` for views
 pub(crate) fn generate_function(
     func_info: &FunctionInfo,
+    name: Ident,
     operation: TokenStream,
     ret_type: TokenStream,
     additional_params: TokenStream,
     use_tx_trait: TokenStream,
+    call_info: TokenStream,
+) -> TokenStream {
+    let serialize_args = json_serialize(func_info); // get the json representation of the arguments list
+    let name_str = func_info.function_name.to_string();
+    let params = get_params(func_info);
+    let output = get_output(func_info);
+    let value = get_value(func_info, &output, &ret_type);
+
+    let tx_call = quote! {
+        // storage measurement before smart-contract function call
+        let storage_usage_before = if self.measure_storage_usage { self.contract.view_account().await?.storage_usage } else { 0 };
+        let started_at = std::time::SystemTime::now();
+        let (res, retries) = #operation // function call will be executed here
+        let finished_at = std::time::SystemTime::now();
+        // storage measurement after the smart-contract function call
+        let storage_usage = if self.measure_storage_usage { Some(self.contract.view_account().await?.storage_usage as i64 - storage_usage_before as i64) } else { None };
+
+        // check the possible failures, the outcomes of the failed call are kept for its statistic
+        if let Err(err) = res.check_res_log_failures() {
+            details = Some(integration_tests_toolset::tx_result::TxResultDetails::from(&res));
+            return Err(err);
+        }
+        // Convert to wrapped value which will also store call statistic
+        #ret_type::from_res(#name_str.to_owned(), #value, storage_usage, res)
+            .map(|tx_result| {
+                tx_result
+                    .with_retries(retries)
+                    .with_timing(started_at, finished_at)
+                    .with_call_info(self.contract.id(), serialized_args.clone(), deposit)
+                    .record_global_statistic()
+            })
+    };
+
+    quote! {
+        pub async fn #name(&self, #params #additional_params) -> integration_tests_toolset::error::Result<integration_tests_toolset::tx_result::TxResult<#output>> {
+            use integration_tests_toolset::{tx_result::FromRes, res_logger::ResLogger, print_log};
+            use integration_tests_toolset::res_logger::OwoColorize;
+            #use_tx_trait
+            #serialize_args
+            let (deposit, caller_id) = #call_info;
+            let mut details = None;
+            let tx_result = async { #tx_call }.await;
+
+            // the failed call is recorded for the batch which runs it
+            if let Err(err) = &tx_result {
+                integration_tests_toolset::statistic::failed_call::record_failed_call(
+                    integration_tests_toolset::statistic::statistic_consumer::Statistic::failed(#name_str, err)
+                        .with_call_info(self.contract.id(), caller_id, serialized_args, deposit)
+                        .with_details(details.unwrap_or_default())
+                );
+            }
+            tx_result
+        }
+    }
+}
+
+// This function is used to generate a binding which submits the Mutable(call) transaction
+// and returns the handle to await its execution later
+pub(crate) fn generate_submit_function(
+    func_info: &FunctionInfo,
+    operation: TokenStream,
+    additional_params: TokenStream,
+    deposit: TokenStream,
 ) -> TokenStream {
     let serialize_args = json_serialize(func_info); // get the json representation of the arguments list
-    let name = func_info.function_name.clone();
+    let name = format_ident!("{}_submit", func_info.function_name);
     let name_str = func_info.function_name.to_string();
+    let params = get_params(func_info);
+    let output = get_output(func_info);
+    let ret_type = quote! {integration_tests_toolset::tx_result::CallResult};
+
+    // the converter of the execution result is executed when the transaction is finished
+    let value_from_res = if output == parse_quote! {()} {
+        quote! {|_res| Ok(())}
+    } else if func_info.output.is_promise {
+        quote! {|res| #ret_type::value_from_res_for_promise(res)}
+    } else {
+        quote! {|res| {
+            use integration_tests_toolset::tx_result::FromRes;
+            #ret_type::value_from_res(res)
+        }}
+    };
+
+    quote! {
+        pub async fn #name(&self, #params #additional_params) -> integration_tests_toolset::error::Result<integration_tests_toolset::pending_tx::submitted_tx::SubmittedTx<#output>> {
+            use integration_tests_toolset::print_log;
+            use integration_tests_toolset::res_logger::OwoColorize;
+            use integration_tests_toolset::pending_tx::{call::Call, signer::TxSigner};
+            #serialize_args
+            let started_at = std::time::SystemTime::now();
+            let (status, retries) = #operation // transaction will be submitted here
+            Ok(integration_tests_toolset::pending_tx::submitted_tx::SubmittedTx::new(#name_str.to_owned(), status, retries, started_at, #value_from_res)
+                .with_call_info(self.contract.id(), serialized_args, #deposit))
+        }
+    }
+}
+
+// Get the list of the function parameters which is ready to be extended with the additional parameters
+fn get_params(func_info: &FunctionInfo) -> Punctuated<FnArg, Comma> {
     let mut params = func_info.params.clone();
 
     // added Comma to the end of the params list in case it is not empty or does not already contains it
@@ -137,16 +276,24 @@ pub(crate) fn generate_function(
         params.push_punct(Comma::default());
     }
 
+    params
+}
+
+// Get the output type of the generated function
+fn get_output(func_info: &FunctionInfo) -> Type {
     let ty = func_info.output.output.clone();
 
     // Handle the case when the output type is PromiseOrValue<T> type
-    let output = if func_info.output.is_promise {
+    if func_info.output.is_promise {
         parse_quote! {Option<#ty>} // substitute to Option<T> instead of PromiseOrValue<T>
     } else {
         ty
-    };
+    }
+}
 
-    let value = if output == parse_quote! {()} {
+// Get the expression which extracts the function value from the execution result stored in res
+fn get_value(func_info: &FunctionInfo, output: &Type, ret_type: &TokenStream) -> TokenStream {
+    if *output == parse_quote! {()} {
         quote! {()}
     } else if func_info.output.is_promise {
         // special case to handle PromiseOrValue<T> will return T
@@ -154,28 +301,5 @@ pub(crate) fn generate_function(
     } else {
         // in this handler we will try to deserialize to T
         quote! {#ret_type::value_from_res(&res)?}
-    };
-
-    let tx_call = quote! {
-        // storage measurement before smart-contract function call
-        let storage_usage_before = if self.measure_storage_usage { self.contract.view_account().await?.storage_usage } else { 0 };
-        let res = #operation // function call will be executed here
-        // storage measurement after the smart-contract function call
-        let storage_usage = if self.measure_storage_usage { Some(self.contract.view_account().await?.storage_usage as i64 - storage_usage_before as i64) } else { None };
-
-        // check the possible failures
-        res.check_res_log_failures()?;
-        // Convert to wrapped value which will also store call statistic
-        #ret_type::from_res(#name_str.to_owned(), #value, storage_usage, res)
-    };
-
-    quote! {
-        pub async fn #name(&self, #params #additional_params) -> integration_tests_toolset::error::Result<integration_tests_toolset::tx_result::TxResult<#output>> {
-            use integration_tests_toolset::{tx_result::FromRes, res_logger::ResLogger, print_log};
-            use integration_tests_toolset::res_logger::OwoColorize;
-            #use_tx_trait
-            #serialize_args
-            #tx_call
-        }
     }
 }
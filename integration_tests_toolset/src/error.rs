@@ -8,6 +8,17 @@ pub enum TestError {
     ExecutionFailure(#[from] Box<workspaces::result::ExecutionFailure>),
     #[error("Internal receipt failure: {:?}", _0)]
     ReceiptFailure(#[from] Box<workspaces::error::ErrorKind>),
+    // failure of the request which is made by the RPC client directly, e.g. for the data which workspaces doesn't expose
+    #[error("RPC error: {}", _0)]
+    Rpc(Box<dyn std::error::Error + Send + Sync>),
+    #[error("Timeout: call has not finished in {:?}", _0)]
+    Timeout(std::time::Duration),
+    #[error("Baseline exceeded: {}", _0)]
+    BaselineExceeded(String),
+    #[error("Budget exceeded: {}", _0)]
+    BudgetExceeded(String),
+    #[error("Snapshot mismatch: {}", _0)]
+    SnapshotMismatch(String),
     #[error("Test error: {}", _0)]
     Custom(String),
 }
@@ -30,4 +41,13 @@ impl From<workspaces::error::ErrorKind> for TestError {
     }
 }
 
+impl<E> From<near_jsonrpc_client::errors::JsonRpcError<E>> for TestError
+where
+    E: std::fmt::Debug + std::fmt::Display + Send + Sync + 'static,
+{
+    fn from(error: near_jsonrpc_client::errors::JsonRpcError<E>) -> Self {
+        TestError::Rpc(Box::new(error))
+    }
+}
+
 pub type Result<T> = std::result::Result<T, TestError>;
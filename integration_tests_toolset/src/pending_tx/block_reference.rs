@@ -0,0 +1,23 @@
+use workspaces::types::CryptoHash;
+
+/// Defines the block, which state should be used for the view call of the NEAR smart-contract
+/// * Latest - the latest state of the blockchain
+/// * Height - the state as of the block with particular height
+/// * Hash - the state as of the block with particular hash
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum BlockReference {
+    #[default]
+    Latest,
+    Height(u64),
+    Hash(CryptoHash),
+}
+
+impl BlockReference {
+    pub fn at_block(height: u64) -> Self {
+        Self::Height(height)
+    }
+
+    pub fn at_hash(hash: CryptoHash) -> Self {
+        Self::Hash(hash)
+    }
+}
@@ -1,9 +1,14 @@
+use crate::error::Result;
 use async_trait::async_trait;
-use workspaces::{result::ExecutionFinalResult, Account};
+use workspaces::{operations::TransactionStatus, result::ExecutionFinalResult, Account};
 
 /// This trait defines the interface for the call method of the NEAR smart-contract
+/// * Note: both methods return the number of retries made according to the retry policy along with the result
 #[async_trait]
 pub trait Call {
     /// Should be used to execute the prepared mutable call on the generated contract template method
-    async fn call(self, caller: &Account) -> workspaces::result::Result<ExecutionFinalResult>;
+    async fn call(self, caller: &Account) -> Result<(ExecutionFinalResult, u32)>;
+
+    /// Should be used to submit the prepared mutable call without waiting for its execution
+    async fn submit(self, caller: &Account) -> Result<(TransactionStatus, u32)>;
 }
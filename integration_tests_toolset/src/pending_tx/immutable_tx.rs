@@ -1,4 +1,7 @@
-use crate::pending_tx::view::View;
+use crate::{
+    error::Result,
+    pending_tx::{block_reference::BlockReference, retry_policy::RetryPolicy, view::View},
+};
 use async_trait::async_trait;
 use workspaces::{result::ViewResultDetails, Contract};
 
@@ -9,6 +12,10 @@ pub struct ImmutablePendingTx<'a> {
     function_name: String,
     // json structured args serialized to bytes
     args: Vec<u8>,
+    // block which state should be queried
+    block_reference: BlockReference,
+    // policy of the RPC call retries
+    retry_policy: RetryPolicy,
 }
 
 impl<'a> ImmutablePendingTx<'a> {
@@ -17,17 +24,39 @@ impl<'a> ImmutablePendingTx<'a> {
             contract,
             function_name,
             args,
+            block_reference: BlockReference::default(),
+            retry_policy: RetryPolicy::current(),
         }
     }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Set the block which state should be used for the view call
+    pub fn at(mut self, block_reference: BlockReference) -> Self {
+        self.block_reference = block_reference;
+        self
+    }
 }
 
 #[async_trait]
 impl<'a> View for ImmutablePendingTx<'a> {
-    async fn view(self) -> workspaces::result::Result<ViewResultDetails> {
-        self.contract
-            .call(&self.function_name)
-            .args(self.args)
-            .view()
+    async fn view(self) -> Result<(ViewResultDetails, u32)> {
+        self.retry_policy
+            .execute(true, || async {
+                let query = self
+                    .contract
+                    .view(&self.function_name)
+                    .args(self.args.clone());
+
+                match &self.block_reference {
+                    BlockReference::Latest => query.await,
+                    BlockReference::Height(height) => query.block_height(*height).await,
+                    BlockReference::Hash(hash) => query.block_hash(*hash).await,
+                }
+            })
             .await
     }
 }
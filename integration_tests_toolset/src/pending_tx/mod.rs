@@ -1,5 +1,9 @@
+pub mod block_reference;
 pub mod call;
 pub mod immutable_tx;
 pub mod mutable_tx;
 pub mod payable_tx;
+pub mod retry_policy;
+pub mod signer;
+pub mod submitted_tx;
 pub mod view;
@@ -1,6 +1,7 @@
-use super::call::Call;
+use super::{call::Call, retry_policy::RetryPolicy};
+use crate::error::Result;
 use async_trait::async_trait;
-use workspaces::{result::ExecutionFinalResult, Account, Contract};
+use workspaces::{operations::TransactionStatus, result::ExecutionFinalResult, Account, Contract};
 
 /// Struct which encapsulates all required arguments to make a state-mutable call to the NEAR smart-contract
 #[derive(Debug)]
@@ -9,6 +10,8 @@ pub struct MutablePendingTx<'a> {
     function_name: String,
     // json structured args serialized to bytes
     args: Vec<u8>,
+    // policy of the RPC call retries
+    retry_policy: RetryPolicy,
 }
 
 impl<'a> MutablePendingTx<'a> {
@@ -17,18 +20,39 @@ impl<'a> MutablePendingTx<'a> {
             contract,
             function_name,
             args,
+            retry_policy: RetryPolicy::current(),
         }
     }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
 }
 
 #[async_trait]
 impl<'a> Call for MutablePendingTx<'a> {
-    async fn call(self, caller: &Account) -> workspaces::result::Result<ExecutionFinalResult> {
-        caller
-            .call(self.contract.id(), &self.function_name)
-            .args(self.args)
-            .max_gas()
-            .transact()
+    async fn call(self, caller: &Account) -> Result<(ExecutionFinalResult, u32)> {
+        self.retry_policy
+            .execute_call(|| {
+                caller
+                    .call(self.contract.id(), &self.function_name)
+                    .args(self.args.clone())
+                    .max_gas()
+                    .transact_async()
+            })
+            .await
+    }
+
+    async fn submit(self, caller: &Account) -> Result<(TransactionStatus, u32)> {
+        self.retry_policy
+            .execute(false, || {
+                caller
+                    .call(self.contract.id(), &self.function_name)
+                    .args(self.args.clone())
+                    .max_gas()
+                    .transact_async()
+            })
             .await
     }
 }
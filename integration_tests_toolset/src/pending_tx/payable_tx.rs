@@ -1,6 +1,7 @@
-use super::call::Call;
+use super::{call::Call, retry_policy::RetryPolicy};
+use crate::error::Result;
 use async_trait::async_trait;
-use workspaces::{result::ExecutionFinalResult, Account, Contract};
+use workspaces::{operations::TransactionStatus, result::ExecutionFinalResult, Account, Contract};
 
 /// Struct which encapsulates all required arguments to make a state-mutable call with the deposit to the NEAR smart-contract
 #[derive(Debug)]
@@ -11,6 +12,8 @@ pub struct PayablePendingTx<'a> {
     args: Vec<u8>,
     // deposit provided by the user
     attached_deposit: u128,
+    // policy of the RPC call retries
+    retry_policy: RetryPolicy,
 }
 
 impl<'a> PayablePendingTx<'a> {
@@ -25,19 +28,41 @@ impl<'a> PayablePendingTx<'a> {
             function_name,
             args,
             attached_deposit,
+            retry_policy: RetryPolicy::current(),
         }
     }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
 }
 
 #[async_trait]
 impl<'a> Call for PayablePendingTx<'a> {
-    async fn call(self, caller: &Account) -> workspaces::result::Result<ExecutionFinalResult> {
-        caller
-            .call(self.contract.id(), &self.function_name)
-            .args(self.args)
-            .deposit(self.attached_deposit)
-            .max_gas()
-            .transact()
+    async fn call(self, caller: &Account) -> Result<(ExecutionFinalResult, u32)> {
+        self.retry_policy
+            .execute_call(|| {
+                caller
+                    .call(self.contract.id(), &self.function_name)
+                    .args(self.args.clone())
+                    .deposit(self.attached_deposit)
+                    .max_gas()
+                    .transact_async()
+            })
+            .await
+    }
+
+    async fn submit(self, caller: &Account) -> Result<(TransactionStatus, u32)> {
+        self.retry_policy
+            .execute(false, || {
+                caller
+                    .call(self.contract.id(), &self.function_name)
+                    .args(self.args.clone())
+                    .deposit(self.attached_deposit)
+                    .max_gas()
+                    .transact_async()
+            })
             .await
     }
 }
@@ -0,0 +1,193 @@
+use crate::error::{Result, TestError};
+use std::{
+    future::Future,
+    sync::RwLock,
+    task::Poll,
+    time::{Duration, Instant},
+};
+use workspaces::{
+    error::{ErrorKind, RpcErrorCode},
+    operations::TransactionStatus,
+    result::ExecutionFinalResult,
+};
+
+// Policy which is used by the pending transactions if no other policy is specified
+static DEFAULT_RETRY_POLICY: RwLock<Option<RetryPolicy>> = RwLock::new(None);
+
+// Interval between the status requests of the broadcast transaction
+const STATUS_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+// Limit of the wait for the broadcast transaction execution if the policy has no timeout,
+// the transaction could be dropped by the node, so its status would be pending forever
+const DEFAULT_EXECUTION_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Struct which defines how the sandbox RPC calls of the pending transactions should be retried
+/// * `max_retries` - number of retries after the first failed attempt
+/// * `initial_backoff` - delay before the first retry, every next delay is multiplied by `backoff_multiplier`
+/// * `max_backoff` - upper limit of the delay between retries
+/// * `timeout` - time limit for the single request, and for the execution of the state-mutable call.
+///   None means no limit for the single request, while the wait for the execution is still limited by 60 seconds
+///
+/// * Note: state-mutable transactions are never sent twice. The broadcast is retried only if the request
+///   has not reached the node, afterwards the status of the transaction is queried by its hash until it is executed.
+///   Nonce errors are retried by workspaces itself, contract failures are returned immediately
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub initial_backoff: Duration,
+    pub backoff_multiplier: u32,
+    pub max_backoff: Duration,
+    pub timeout: Option<Duration>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(200),
+            backoff_multiplier: 2,
+            max_backoff: Duration::from_secs(5),
+            timeout: Some(Duration::from_secs(60)),
+        }
+    }
+}
+
+/// Effect of the failed RPC request on the transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailureKind {
+    /// The request has not reached the node, e.g. the connection failure, so it could be sent again
+    NotSent,
+    /// The request could be processed by the node, e.g. the IO failure after the broadcast,
+    /// so only the idempotent requests (views and status queries) could be sent again
+    Ambiguous,
+    /// Failure of the request itself, e.g. the contract panic or the invalid query, it is never retried
+    Fatal,
+}
+
+impl RetryPolicy {
+    /// Policy without any retries and timeouts
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            timeout: None,
+            ..Default::default()
+        }
+    }
+
+    /// Set the policy which should be used by all pending transactions created after this call
+    pub fn set_default(policy: RetryPolicy) {
+        *DEFAULT_RETRY_POLICY.write().unwrap() = Some(policy);
+    }
+
+    /// Get the policy which is used by the pending transactions by default
+    pub fn current() -> RetryPolicy {
+        DEFAULT_RETRY_POLICY
+            .read()
+            .unwrap()
+            .clone()
+            .unwrap_or_default()
+    }
+
+    /// Delay before the retry with the given number, starting from 1
+    pub fn backoff(&self, retry: u32) -> Duration {
+        (1..retry).fold(self.initial_backoff.min(self.max_backoff), |backoff, _| {
+            (backoff * self.backoff_multiplier).min(self.max_backoff)
+        })
+    }
+
+    /// Time limit of the wait for the broadcast transaction execution
+    pub fn execution_timeout(&self) -> Duration {
+        self.timeout.unwrap_or(DEFAULT_EXECUTION_TIMEOUT)
+    }
+
+    /// Execute the request according to the policy
+    /// Returns the request result along with the number of made retries
+    /// * `idempotent` - whether the request could be sent again after the ambiguous failure or timeout
+    pub async fn execute<T, F, Fut>(&self, idempotent: bool, mut op: F) -> Result<(T, u32)>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = workspaces::result::Result<T>>,
+    {
+        let is_retryable = |kind: FailureKind| match kind {
+            FailureKind::NotSent => true,
+            FailureKind::Ambiguous => idempotent,
+            FailureKind::Fatal => false,
+        };
+        let mut retries = 0;
+
+        loop {
+            let (err, kind): (TestError, _) = match self.timeout {
+                Some(timeout) => match tokio::time::timeout(timeout, op()).await {
+                    Ok(Ok(res)) => return Ok((res, retries)),
+                    Ok(Err(err)) => {
+                        let kind = failure_kind(&err);
+                        (err.into(), kind)
+                    }
+                    Err(_) => (TestError::Timeout(timeout), FailureKind::Ambiguous),
+                },
+                None => match op().await {
+                    Ok(res) => return Ok((res, retries)),
+                    Err(err) => {
+                        let kind = failure_kind(&err);
+                        (err.into(), kind)
+                    }
+                },
+            };
+
+            if !is_retryable(kind) || retries >= self.max_retries {
+                return Err(err);
+            }
+
+            retries += 1;
+            tokio::time::sleep(self.backoff(retries)).await;
+        }
+    }
+
+    /// Execute the state-mutable call: the transaction is broadcast once and its status is queried by the hash
+    /// until it is executed, so the ambiguous failures never lead to the second execution of the transaction.
+    /// Returns the execution result along with the number of made retries of all requests
+    pub async fn execute_call<F, Fut>(&self, broadcast: F) -> Result<(ExecutionFinalResult, u32)>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = workspaces::result::Result<TransactionStatus>>,
+    {
+        let (status, retries) = self.execute(false, broadcast).await?;
+        let (res, status_retries) = self.wait_for_execution(&status).await?;
+        Ok((res, retries + status_retries))
+    }
+
+    /// Query the status of the broadcast transaction until it is executed, the status requests are retried
+    /// according to the policy and the whole wait is limited by the `execution_timeout`.
+    /// Returns the execution result along with the number of made retries of the status requests
+    pub async fn wait_for_execution(
+        &self,
+        status: &TransactionStatus,
+    ) -> Result<(ExecutionFinalResult, u32)> {
+        let started_at = Instant::now();
+        let mut retries = 0;
+
+        loop {
+            let (poll, status_retries) = self.execute(true, || status.status()).await?;
+            retries += status_retries;
+            if let Poll::Ready(res) = poll {
+                return Ok((res, retries));
+            }
+
+            let timeout = self.execution_timeout();
+            if started_at.elapsed() >= timeout {
+                return Err(TestError::Timeout(timeout));
+            }
+            tokio::time::sleep(STATUS_POLL_INTERVAL).await;
+        }
+    }
+}
+
+/// Classify the failed RPC request by the workspaces error kind
+pub fn failure_kind(err: &workspaces::error::Error) -> FailureKind {
+    match err.kind() {
+        ErrorKind::Rpc(RpcErrorCode::ConnectionFailure)
+        | ErrorKind::Rpc(RpcErrorCode::UnableToRetrieveAccessKey) => FailureKind::NotSent,
+        ErrorKind::Rpc(RpcErrorCode::BroadcastTxFailure) | ErrorKind::Io => FailureKind::Ambiguous,
+        _ => FailureKind::Fatal,
+    }
+}
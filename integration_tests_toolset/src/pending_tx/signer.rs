@@ -0,0 +1,60 @@
+use workspaces::{network::Network, types::SecretKey, Account, AccountId, Contract, Worker};
+
+/// This trait defines the account which signs the transaction of the generated contract template method
+/// * Note: it is implemented for the workspaces::Account, so the account full-access key is used by default
+pub trait TxSigner: Send + Sync {
+    /// Account which holds the key that should be used for the transaction signing
+    fn signer(&self) -> &Account;
+}
+
+impl TxSigner for Account {
+    fn signer(&self) -> &Account {
+        self
+    }
+}
+
+impl TxSigner for Contract {
+    fn signer(&self) -> &Account {
+        self.as_account()
+    }
+}
+
+impl<T: TxSigner + ?Sized> TxSigner for &T {
+    fn signer(&self) -> &Account {
+        (**self).signer()
+    }
+}
+
+/// Struct which encapsulates an explicit signer of the transaction: the account id and the secret key.
+/// It should be used in cases when the transaction should be signed with the key different from the
+/// account full-access key (ex. function-call access key with the limited allowance and methods list)
+#[derive(Debug, Clone)]
+pub struct KeySigner {
+    account: Account,
+}
+
+impl KeySigner {
+    pub fn new(
+        worker: &Worker<impl Network + 'static>,
+        account_id: AccountId,
+        secret_key: SecretKey,
+    ) -> Self {
+        Self {
+            account: Account::from_secret_key(account_id, secret_key, worker),
+        }
+    }
+
+    pub fn account_id(&self) -> &AccountId {
+        self.account.id()
+    }
+
+    pub fn secret_key(&self) -> &SecretKey {
+        self.account.secret_key()
+    }
+}
+
+impl TxSigner for KeySigner {
+    fn signer(&self) -> &Account {
+        &self.account
+    }
+}
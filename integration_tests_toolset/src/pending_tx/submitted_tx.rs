@@ -0,0 +1,149 @@
+use super::retry_policy::RetryPolicy;
+use crate::{
+    error::{Result, TestError},
+    res_logger::ResLogger,
+    statistic::{failed_call::record_failed_call, statistic_consumer::Statistic},
+    tx_result::{CallResult, FromRes, TxResult, TxResultDetails},
+};
+use std::{task::Poll, time::SystemTime};
+use workspaces::{
+    operations::TransactionStatus,
+    result::ExecutionFinalResult,
+    types::{Balance, CryptoHash},
+    AccountId,
+};
+
+/// Handle of the submitted state-mutable call of the NEAR smart-contract.
+/// It allows to keep several transactions in flight and await their results in the controlled order.
+/// The status requests are retried according to the retry policy, which is the default one unless it is set.
+/// The failed call is recorded the same way as the failure of the generated call, e.g. for the batch statistic
+/// * Note: storage usage is not measured for the submitted transactions,
+///   the call is finished when its execution result is received
+#[derive(Debug)]
+pub struct SubmittedTx<T> {
+    func_name: String,
+    status: TransactionStatus,
+    // number of the submission retries made according to the retry policy
+    retries: u32,
+    // policy of the status requests retries and of the execution timeout
+    retry_policy: RetryPolicy,
+    // wall-clock time before the transaction submission
+    started_at: SystemTime,
+    // contract, serialized JSON arguments and attached deposit of the call
+    call_info: Option<(AccountId, String, Balance)>,
+    // converter of the execution result to the value returned by the smart-contract method
+    value_from_res: fn(&ExecutionFinalResult) -> Result<T>,
+}
+
+impl<T> SubmittedTx<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    pub fn new(
+        func_name: String,
+        status: TransactionStatus,
+        retries: u32,
+        started_at: SystemTime,
+        value_from_res: fn(&ExecutionFinalResult) -> Result<T>,
+    ) -> Self {
+        Self {
+            func_name,
+            status,
+            retries,
+            retry_policy: RetryPolicy::current(),
+            started_at,
+            value_from_res,
+            call_info: None,
+        }
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Set the contract, serialized JSON arguments and attached deposit of the call
+    pub fn with_call_info(
+        mut self,
+        contract_id: &AccountId,
+        args: String,
+        deposit: Balance,
+    ) -> Self {
+        self.call_info = Some((contract_id.clone(), args, deposit));
+        self
+    }
+
+    pub fn func_name(&self) -> &str {
+        &self.func_name
+    }
+
+    /// Hash of the submitted transaction
+    pub fn hash(&self) -> &CryptoHash {
+        self.status.hash()
+    }
+
+    /// Check the status of the transaction.
+    /// Returns Poll::Pending in case the transaction is not executed yet
+    pub async fn status(&self) -> Result<Poll<TxResult<T>>> {
+        let poll = match self
+            .retry_policy
+            .execute(true, || self.status.status())
+            .await
+        {
+            Ok((poll, retries)) => poll.map(|res| (res, retries)),
+            Err(err) => return Err(self.record_failure(err, None)),
+        };
+        match poll {
+            Poll::Ready((res, retries)) => self.to_tx_result(res, retries).map(Poll::Ready),
+            Poll::Pending => Ok(Poll::Pending),
+        }
+    }
+
+    /// Wait for the transaction execution and convert it to the TxResult.
+    /// The wait is limited by the timeout of the retry policy
+    pub async fn wait(self) -> Result<TxResult<T>> {
+        match self.retry_policy.wait_for_execution(&self.status).await {
+            Ok((res, retries)) => self.to_tx_result(res, retries),
+            Err(err) => Err(self.record_failure(err, None)),
+        }
+    }
+
+    fn to_tx_result(&self, res: ExecutionFinalResult, status_retries: u32) -> Result<TxResult<T>> {
+        // check the possible failures, the outcomes of the failed call are kept for its statistic
+        let value = match res
+            .check_res_log_failures()
+            .and_then(|_| (self.value_from_res)(&res))
+        {
+            Ok(value) => value,
+            Err(err) => return Err(self.record_failure(err, Some(&res))),
+        };
+        CallResult::from_res(self.func_name.clone(), value, None, res).map(|tx_result| {
+            let tx_result = tx_result
+                .with_retries(self.retries + status_retries)
+                .with_timing(self.started_at, SystemTime::now());
+            match &self.call_info {
+                Some((contract_id, args, deposit)) => {
+                    tx_result.with_call_info(contract_id, args.clone(), Some(*deposit))
+                }
+                None => tx_result,
+            }
+            .record_global_statistic()
+        })
+    }
+
+    // Record the failed call for the batch which runs it, the error is returned unchanged
+    fn record_failure(&self, err: TestError, res: Option<&ExecutionFinalResult>) -> TestError {
+        let stat = Statistic::failed(&self.func_name, &err)
+            .with_details(res.map(TxResultDetails::from).unwrap_or_default());
+        record_failed_call(match &self.call_info {
+            Some((contract_id, args, deposit)) => stat.with_call_info(
+                contract_id,
+                Some(self.status.sender_id().clone()),
+                args.clone(),
+                Some(*deposit),
+            ),
+            None => stat,
+        });
+        err
+    }
+}
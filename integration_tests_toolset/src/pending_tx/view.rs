@@ -1,3 +1,4 @@
+use crate::error::Result;
 use async_trait::async_trait;
 use workspaces::result::ViewResultDetails;
 
@@ -5,5 +6,6 @@ use workspaces::result::ViewResultDetails;
 #[async_trait]
 pub trait View {
     /// Should be used to execute the prepared immutable call on the generated contract template method
-    async fn view(self) -> workspaces::result::Result<ViewResultDetails>;
+    /// Returns the number of retries made according to the retry policy along with the result
+    async fn view(self) -> Result<(ViewResultDetails, u32)>;
 }
@@ -0,0 +1,262 @@
+use super::{
+    mode_printer::ModePrinter,
+    numeric_aggregator::{NumericStatistic, NumericValue},
+    report::{render_report, ReportCell, ReportFormat, ReportTable},
+    statistic_printer::StatisticPrinter,
+};
+use crate::error::{Result, TestError};
+use owo_colors::OwoColorize;
+use serde_json::{json, Map, Value};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::Path,
+};
+
+/// Baseline values of the particular function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BaselineEntry {
+    pub min: i64,
+    pub median: i64,
+    pub max: i64,
+}
+
+impl<V: NumericValue> From<&NumericStatistic<V>> for BaselineEntry {
+    fn from(stat: &NumericStatistic<V>) -> Self {
+        Self {
+            min: stat.min.to_f64() as i64,
+            median: stat.median.to_f64() as i64,
+            max: stat.max.to_f64() as i64,
+        }
+    }
+}
+
+/// Baseline file with the per-function values of every section (gas, storage)
+/// The file is stored as JSON: `{"gas": {"function": {"min": 1, "median": 2, "max": 3}}, "storage": {...}}`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baseline {
+    pub sections: BTreeMap<String, BTreeMap<String, BaselineEntry>>,
+}
+
+impl Baseline {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let content = fs::read_to_string(path).map_err(|err| TestError::Custom(err.to_string()))?;
+        let json: Value =
+            serde_json::from_str(&content).map_err(|err| TestError::Custom(err.to_string()))?;
+
+        let invalid = || TestError::Custom("Invalid baseline file format".to_owned());
+        let mut sections = BTreeMap::new();
+        for (section, entries) in json.as_object().ok_or_else(invalid)? {
+            let mut section_entries = BTreeMap::new();
+            for (func, entry) in entries.as_object().ok_or_else(invalid)? {
+                let value = |key: &str| entry[key].as_i64().ok_or_else(invalid);
+                section_entries.insert(
+                    func.clone(),
+                    BaselineEntry {
+                        min: value("min")?,
+                        median: value("median")?,
+                        max: value("max")?,
+                    },
+                );
+            }
+            sections.insert(section.clone(), section_entries);
+        }
+
+        Ok(Self { sections })
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let json: Map<String, Value> = self
+            .sections
+            .iter()
+            .map(|(section, entries)| {
+                let entries: Map<String, Value> = entries
+                    .iter()
+                    .map(|(func, entry)| {
+                        (
+                            func.clone(),
+                            json!({ "min": entry.min, "median": entry.median, "max": entry.max }),
+                        )
+                    })
+                    .collect();
+                (section.clone(), Value::Object(entries))
+            })
+            .collect();
+
+        let content = serde_json::to_string_pretty(&Value::Object(json))
+            .map_err(|err| TestError::Custom(err.to_string()))?;
+        fs::write(path, content + "\n").map_err(|err| TestError::Custom(err.to_string()))
+    }
+}
+
+/// Result of the comparison of the functions with the baseline
+/// * `table` - deltas of the medians of every function in the baseline or in the current run
+/// * `exceeded` - functions which exceed the tolerance, along with the delta in percents
+/// * `missing` - functions of the baseline which are absent in the current run
+#[derive(Debug, Clone, PartialEq)]
+pub struct BaselineComparison {
+    pub section: String,
+    pub tolerance_percent: f64,
+    pub table: ReportTable,
+    pub exceeded: Vec<(String, f64)>,
+    pub missing: Vec<String>,
+}
+
+impl BaselineComparison {
+    /// Render the comparison table in the particular format
+    pub fn make_report(&self, format: ReportFormat) -> String {
+        render_report(std::slice::from_ref(&self.table), format, true)
+    }
+
+    /// Returns the error in case any function exceeds the tolerance
+    pub fn check(&self) -> Result<()> {
+        if self.exceeded.is_empty() {
+            return Ok(());
+        }
+
+        Err(TestError::BaselineExceeded(format!(
+            "{} tolerance {}% is exceeded by: {}",
+            self.section,
+            self.tolerance_percent,
+            self.exceeded
+                .iter()
+                .map(|(func, delta_percent)| format!("{} ({:+.2}%)", func, delta_percent))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )))
+    }
+}
+
+/// Interface for saving the aggregated statistic as the baseline and comparing with it.
+/// Medians of the functions are compared, the tolerance is defined in percents of the baseline value
+/// * Note: several consumers could share the same baseline file, each of them updates only its own section
+pub trait BaselineComparator: StatisticPrinter {
+    /// Name of the section in the baseline file
+    fn baseline_section(&self) -> &'static str;
+
+    /// Current values of every function
+    fn baseline_entries(&self) -> BTreeMap<String, BaselineEntry>;
+
+    fn save_baseline(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let mut baseline = if path.exists() {
+            Baseline::load(path)?
+        } else {
+            Baseline::default()
+        };
+
+        baseline
+            .sections
+            .insert(self.baseline_section().to_owned(), self.baseline_entries());
+        baseline.save(path)
+    }
+
+    /// Deltas of the functions relative to the baseline, the functions which are new
+    /// or missing in the current run are reported as well
+    fn baseline_comparison(
+        &self,
+        path: impl AsRef<Path>,
+        tolerance_percent: f64,
+    ) -> Result<BaselineComparison> {
+        let baseline = Baseline::load(path)?;
+        let section = self.baseline_section();
+        let baseline_entries = baseline.sections.get(section).cloned().unwrap_or_default();
+        let current_entries = self.baseline_entries();
+
+        let mut comparison = BaselineComparison {
+            section: section.to_owned(),
+            tolerance_percent,
+            table: ReportTable::new(
+                &format!("Baseline comparison: {}", section),
+                [
+                    "Function", "Baseline", "Current", "Delta", "Delta %", "Status",
+                ]
+                .iter()
+                .map(|title| title.to_string())
+                .collect(),
+            ),
+            exceeded: vec![],
+            missing: vec![],
+        };
+
+        let funcs: BTreeSet<&String> = baseline_entries
+            .keys()
+            .chain(current_entries.keys())
+            .collect();
+        for func in funcs {
+            let func_cell = ReportCell::new(func.green().bold(), func.as_str());
+            let (base, current) = match (baseline_entries.get(func), current_entries.get(func)) {
+                (Some(base), Some(current)) => (base, current),
+                (None, Some(current)) => {
+                    comparison.table.add_row(vec![
+                        func_cell,
+                        ReportCell::new("-", Value::Null),
+                        ReportCell::new(current.median, current.median),
+                        ReportCell::new("-", Value::Null),
+                        ReportCell::new("-", Value::Null),
+                        ReportCell::new("new".blue().bold(), "new"),
+                    ]);
+                    continue;
+                }
+                (Some(base), None) => {
+                    comparison.missing.push(func.clone());
+                    comparison.table.add_row(vec![
+                        func_cell,
+                        ReportCell::new(base.median, base.median),
+                        ReportCell::new("-", Value::Null),
+                        ReportCell::new("-", Value::Null),
+                        ReportCell::new("-", Value::Null),
+                        ReportCell::new("missing".yellow().bold(), "missing"),
+                    ]);
+                    continue;
+                }
+                (None, None) => continue,
+            };
+
+            let delta = current.median - base.median;
+            let delta_percent = if base.median == 0 {
+                if delta == 0 {
+                    0.
+                } else {
+                    f64::INFINITY.copysign(delta as f64)
+                }
+            } else {
+                delta as f64 / base.median.unsigned_abs() as f64 * 100.
+            };
+            let is_exceeded = delta_percent > tolerance_percent;
+            if is_exceeded {
+                comparison.exceeded.push((func.clone(), delta_percent));
+            }
+
+            comparison.table.add_row(vec![
+                func_cell,
+                ReportCell::new(base.median, base.median),
+                ReportCell::new(current.median, current.median),
+                ReportCell::new(format!("{:+}", delta), delta),
+                ReportCell::new(
+                    format!("{:+.2}%", delta_percent),
+                    json!((delta_percent * 100.).round() / 100.),
+                ),
+                if is_exceeded {
+                    ReportCell::new("exceeded".red().bold(), "exceeded")
+                } else {
+                    ReportCell::new("ok".green().bold(), "ok")
+                },
+            ]);
+        }
+
+        Ok(comparison)
+    }
+
+    /// Print the deltas of the functions relative to the baseline to the console,
+    /// the report of the consumer itself is not affected.
+    /// Returns the error in case any function exceeds the tolerance
+    fn compare_with_baseline(&self, path: impl AsRef<Path>, tolerance_percent: f64) -> Result<()> {
+        let comparison = self.baseline_comparison(path, tolerance_percent)?;
+        ModePrinter::Console.print_report(
+            std::slice::from_ref(&comparison.table),
+            self.get_report_format(),
+        )?;
+        comparison.check()
+    }
+}
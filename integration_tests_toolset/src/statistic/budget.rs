@@ -0,0 +1,251 @@
+use super::{
+    mode_printer::ModePrinter,
+    report::{ReportCell, ReportFormat, ReportTable},
+    statistic_consumer::{Statistic, StatisticConsumer},
+    statistic_printer::StatisticPrinter,
+};
+use crate::{
+    error::{Result, TestError},
+    tx_result::TxResultDetails,
+};
+use owo_colors::OwoColorize;
+use std::{
+    collections::HashMap,
+    fmt::{self, Display, Formatter},
+};
+use workspaces::types::Gas;
+
+/// Limits of the single call of the particular method, unset limits are not checked.
+/// The failed calls are checked the same way, since they burn the gas too
+/// * `max_storage` - limit of the storage usage of the contract in bytes
+/// * `max_receipts` - limit of the number of receipts produced by the call
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MethodBudget {
+    pub max_gas: Option<Gas>,
+    pub max_storage: Option<i64>,
+    pub max_receipts: Option<usize>,
+}
+
+impl MethodBudget {
+    pub fn with_max_gas(mut self, max_gas: Gas) -> Self {
+        self.max_gas = Some(max_gas);
+        self
+    }
+
+    pub fn with_max_storage(mut self, max_storage: i64) -> Self {
+        self.max_storage = Some(max_storage);
+        self
+    }
+
+    pub fn with_max_receipts(mut self, max_receipts: usize) -> Self {
+        self.max_receipts = Some(max_receipts);
+        self
+    }
+}
+
+/// Exceeded limit with the budget and the actual value of the call
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BudgetLimit {
+    Gas { limit: Gas, actual: Gas },
+    Storage { limit: i64, actual: i64 },
+    Receipts { limit: usize, actual: usize },
+}
+
+impl BudgetLimit {
+    pub fn title(&self) -> &'static str {
+        match self {
+            Self::Gas { .. } => "Gas",
+            Self::Storage { .. } => "Storage",
+            Self::Receipts { .. } => "Receipts",
+        }
+    }
+
+    fn cells(&self) -> [ReportCell; 2] {
+        match *self {
+            Self::Gas { limit, actual } => [
+                ReportCell::new(print_gas(limit), limit),
+                ReportCell::new(print_gas(actual).red().bold(), actual),
+            ],
+            Self::Storage { limit, actual } => [
+                ReportCell::new(format!("{} bytes", limit), limit),
+                ReportCell::new(format!("{} bytes", actual).red().bold(), actual),
+            ],
+            Self::Receipts { limit, actual } => [
+                ReportCell::new(limit, limit),
+                ReportCell::new(actual.red().bold(), actual),
+            ],
+        }
+    }
+}
+
+fn print_gas(gas: Gas) -> String {
+    format!("{:.3} Tgas", gas as f64 / 1_000_000_000_000.)
+}
+
+impl Display for BudgetLimit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Gas { limit, actual } => {
+                write!(f, "gas {} > {}", print_gas(actual), print_gas(limit))
+            }
+            Self::Storage { limit, actual } => {
+                write!(f, "storage {} bytes > {} bytes", actual, limit)
+            }
+            Self::Receipts { limit, actual } => write!(f, "receipts {} > {}", actual, limit),
+        }
+    }
+}
+
+/// Call of the method which exceeded its budget
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BudgetViolation {
+    pub func_name: String,
+    pub limit: BudgetLimit,
+}
+
+impl Display for BudgetViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.func_name, self.limit)
+    }
+}
+
+/// Statistic consumer which checks every consumed call against the limits of its method
+/// and records the violations, so the scenario could be finished with `assert_within_limits`.
+/// Methods without the own budget are checked against the default one, if it is set
+#[derive(Debug, Default)]
+pub struct Budget {
+    budgets: HashMap<String, MethodBudget>,
+    default_budget: Option<MethodBudget>,
+    pub violations: Vec<BudgetViolation>,
+    mode_printer: ModePrinter,
+    report_format: ReportFormat,
+}
+
+impl Budget {
+    pub fn new(mode_printer: ModePrinter) -> Self {
+        Self {
+            mode_printer,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_report_format(mut self, report_format: ReportFormat) -> Self {
+        self.report_format = report_format;
+        self
+    }
+
+    /// Set the limits of the particular method
+    pub fn with_method_budget(mut self, func_name: &str, budget: MethodBudget) -> Self {
+        self.budgets.insert(func_name.to_owned(), budget);
+        self
+    }
+
+    /// Set the limits of the methods without the own budget
+    pub fn with_default_budget(mut self, budget: MethodBudget) -> Self {
+        self.default_budget = Some(budget);
+        self
+    }
+
+    /// Returns the error with the list of every call which exceeded its budget, one violation per line
+    pub fn assert_within_limits(&self) -> Result<()> {
+        if self.violations.is_empty() {
+            return Ok(());
+        }
+
+        Err(TestError::BudgetExceeded(format!(
+            "{} call(s) exceeded the budget:\n{}",
+            self.violations.len(),
+            self.violations
+                .iter()
+                .map(|violation| violation.to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        )))
+    }
+}
+
+impl StatisticConsumer for Budget {
+    fn consume_statistic(&mut self, stat: &Statistic) {
+        let Some(budget) = self
+            .budgets
+            .get(&stat.func_name)
+            .or(self.default_budget.as_ref())
+            .copied()
+        else {
+            return;
+        };
+
+        let mut limits = vec![];
+        if let (Some(limit), Some(actual)) = (budget.max_storage, stat.storage_usage) {
+            limits.push(BudgetLimit::Storage { limit, actual });
+        }
+        // the failed calls are checked as well, their outcomes are kept in the details
+        if let TxResultDetails::Call(call_data) = &stat.details {
+            if let Some(limit) = budget.max_gas {
+                limits.push(BudgetLimit::Gas {
+                    limit,
+                    actual: call_data.gas,
+                });
+            }
+            if let Some(limit) = budget.max_receipts {
+                limits.push(BudgetLimit::Receipts {
+                    limit,
+                    actual: call_data.receipt_outcomes.len(),
+                });
+            }
+        }
+
+        self.violations.extend(
+            limits
+                .into_iter()
+                .filter(|limit| match *limit {
+                    BudgetLimit::Gas { limit, actual } => actual > limit,
+                    BudgetLimit::Storage { limit, actual } => actual > limit,
+                    BudgetLimit::Receipts { limit, actual } => actual > limit,
+                })
+                .map(|limit| BudgetViolation {
+                    func_name: stat.func_name.clone(),
+                    limit,
+                }),
+        );
+    }
+
+    fn clean_statistic(&mut self) {
+        self.violations.clear();
+    }
+}
+
+impl StatisticPrinter for Budget {
+    fn get_printer_mode(&self) -> &ModePrinter {
+        &self.mode_printer
+    }
+
+    fn get_report_format(&self) -> ReportFormat {
+        self.report_format
+    }
+
+    fn report_tables(&self) -> Vec<ReportTable> {
+        let mut table = ReportTable::new(
+            "Budget violations",
+            ["Function", "Limit", "Budget", "Actual"]
+                .iter()
+                .map(|title| title.to_string())
+                .collect(),
+        );
+        for violation in self.violations.iter() {
+            let mut cells = vec![
+                ReportCell::new(
+                    violation.func_name.green().bold(),
+                    violation.func_name.as_str(),
+                ),
+                ReportCell::new(
+                    violation.limit.title().blue().bold(),
+                    violation.limit.title(),
+                ),
+            ];
+            cells.extend(violation.limit.cells());
+            table.add_row(cells);
+        }
+        vec![table]
+    }
+}
@@ -1,17 +1,21 @@
 use super::mode_printer::ModePrinter;
 use crate::statistic::{
+    group_by::GroupBy,
+    report::{ReportCell, ReportFormat, ReportTable},
     statistic_consumer::{Statistic, StatisticConsumer},
     statistic_printer::StatisticPrinter,
 };
 use owo_colors::OwoColorize;
-use prettytable::{row, Table};
 use std::collections::HashMap;
 
 /// Structure to store the statistical data about the number of calls for particular function
+/// The calls could be counted per label of the operation instead of the function with `with_group_by`
 #[derive(Debug)]
 pub struct CallCounter {
     pub func_count: HashMap<String, u64>,
+    group_by: GroupBy,
     mode_printer: ModePrinter,
+    report_format: ReportFormat,
 }
 
 impl CallCounter {
@@ -19,16 +23,31 @@ impl CallCounter {
     pub fn new(mode_printer: ModePrinter) -> Self {
         Self {
             func_count: HashMap::new(),
+            group_by: GroupBy::default(),
             mode_printer,
+            report_format: ReportFormat::default(),
         }
     }
+
+    pub fn with_report_format(mut self, report_format: ReportFormat) -> Self {
+        self.report_format = report_format;
+        self
+    }
+
+    /// Count the calls per function, label of the operation or both
+    pub fn with_group_by(mut self, group_by: GroupBy) -> Self {
+        self.group_by = group_by;
+        self
+    }
 }
 
 impl Default for CallCounter {
     fn default() -> Self {
         Self {
             func_count: HashMap::new(),
+            group_by: GroupBy::default(),
             mode_printer: Default::default(),
+            report_format: Default::default(),
         }
     }
 }
@@ -38,23 +57,32 @@ impl StatisticPrinter for CallCounter {
         &self.mode_printer
     }
 
-    fn make_report(&self) -> String {
+    fn get_report_format(&self) -> ReportFormat {
+        self.report_format
+    }
+
+    fn report_tables(&self) -> Vec<ReportTable> {
         let mut count_stat_vec: Vec<_> = self.func_count.iter().collect();
 
         count_stat_vec.sort_by(|a, b| b.1.cmp(&a.1));
 
-        let mut table = Table::new();
-        table.add_row(row!["Function", "Count"]);
+        let mut table = ReportTable::new(
+            "Number of calls",
+            vec![self.group_by.title().to_owned(), "Count".to_owned()],
+        );
         for (func_name, count) in count_stat_vec.iter() {
-            table.add_row(row![func_name.green().bold(), count.blue()]);
+            table.add_row(vec![
+                ReportCell::new(func_name.green().bold(), func_name.as_str()),
+                ReportCell::new(count.blue(), **count),
+            ]);
         }
-        format!("{}\n{}", "Number of calls".bright_yellow().bold(), table)
+        vec![table]
     }
 }
 
 impl StatisticConsumer for CallCounter {
     fn consume_statistic(&mut self, stat: &Statistic) {
-        let count = self.func_count.entry(stat.func_name.clone()).or_insert(0);
+        let count = self.func_count.entry(self.group_by.key(stat)).or_insert(0);
         *count += 1;
     }
 
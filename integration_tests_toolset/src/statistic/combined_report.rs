@@ -0,0 +1,153 @@
+use super::{
+    report::{escape_html, html_page, html_tables, render_report, ReportFormat, ReportTable},
+    statistic_printer::StatisticPrinter,
+};
+use crate::error::{Result, TestError};
+use serde_json::{json, Value};
+use std::{
+    fs::{File, OpenOptions},
+    io::Write,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Report of the whole test run, which combines the reports of several consumers into one file.
+/// The report starts with the run header (name and UTC timestamp),
+/// every consumer's report is placed into its own section with the header
+#[derive(Debug, Clone)]
+pub struct CombinedReport {
+    run_name: String,
+    timestamp: String,
+    report_format: ReportFormat,
+    sections: Vec<(String, Vec<ReportTable>)>,
+}
+
+impl CombinedReport {
+    pub fn new(run_name: &str) -> Self {
+        Self {
+            run_name: run_name.to_owned(),
+            timestamp: utc_timestamp(SystemTime::now()),
+            report_format: ReportFormat::default(),
+            sections: vec![],
+        }
+    }
+
+    pub fn with_report_format(mut self, report_format: ReportFormat) -> Self {
+        self.report_format = report_format;
+        self
+    }
+
+    /// Add the section with the report of the consumer
+    pub fn add_consumer(&mut self, consumer: &(impl StatisticPrinter + ?Sized)) {
+        self.add_section(&consumer.report_name(), consumer.report_tables());
+    }
+
+    pub fn add_section(&mut self, title: &str, tables: Vec<ReportTable>) {
+        self.sections.push((title.to_owned(), tables));
+    }
+
+    /// Render the report without the colour codes
+    pub fn render(&self) -> String {
+        let run_title = format!("{} ({})", self.run_name, self.timestamp);
+        match self.report_format {
+            ReportFormat::Json => {
+                let sections: Vec<Value> = self
+                    .sections
+                    .iter()
+                    .map(|(title, tables)| {
+                        json!({
+                            "title": title,
+                            "tables": tables.iter().map(ReportTable::to_json).collect::<Vec<_>>(),
+                        })
+                    })
+                    .collect();
+                let report = json!({
+                    "run": self.run_name,
+                    "timestamp": self.timestamp,
+                    "sections": sections,
+                });
+                serde_json::to_string_pretty(&report).unwrap_or_default() + "\n"
+            }
+            ReportFormat::Html => {
+                let body: String = self
+                    .sections
+                    .iter()
+                    .map(|(title, tables)| {
+                        format!("<h2>{}</h2>\n{}", escape_html(title), html_tables(tables))
+                    })
+                    .collect();
+                html_page(&run_title, &body)
+            }
+            format => {
+                let (run_header, section_header): (String, fn(&str) -> String) = match format {
+                    ReportFormat::Markdown => (format!("# {}\n", run_title), |title| {
+                        format!("## {}\n", title)
+                    }),
+                    ReportFormat::Csv => (format!("# Run: {}\n", run_title), |title| {
+                        format!("# == {} ==\n", title)
+                    }),
+                    _ => (format!("===== Run: {} =====\n", run_title), |title| {
+                        format!("----- {} -----\n", title)
+                    }),
+                };
+
+                let mut report = run_header;
+                for (title, tables) in self.sections.iter() {
+                    report.push('\n');
+                    report.push_str(&section_header(title));
+                    report.push_str(&render_report(tables, format, false));
+                }
+                report
+            }
+        }
+    }
+
+    /// Write the report into the file, the previous content of the file is truncated
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<()> {
+        Self::write_to(File::create(path), &self.render())
+    }
+
+    /// Append the report to the file, so the reports of the several runs are kept
+    /// * Note: the HTML report is the whole page, so it should be written into the separate file
+    pub fn append(&self, path: impl AsRef<Path>) -> Result<()> {
+        Self::write_to(
+            OpenOptions::new().create(true).append(true).open(path),
+            &(self.render() + "\n"),
+        )
+    }
+
+    fn write_to(file: std::io::Result<File>, report: &str) -> Result<()> {
+        file.and_then(|mut file| file.write_all(report.as_bytes()))
+            .map_err(|err| TestError::Custom(err.to_string()))
+    }
+}
+
+// Format the time as `YYYY-MM-DD HH:MM:SS UTC`
+fn utc_timestamp(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs() as i64)
+        .unwrap_or_default();
+    let (days, secs_of_day) = (secs.div_euclid(86_400), secs.rem_euclid(86_400));
+
+    // conversion of the days since the epoch to the civil date
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        secs_of_day / 3_600,
+        secs_of_day % 3_600 / 60,
+        secs_of_day % 60
+    )
+}
@@ -0,0 +1,179 @@
+use super::{
+    mode_printer::ModePrinter,
+    report::{ReportCell, ReportFormat, ReportTable},
+    statistic_consumer::{Statistic, StatisticConsumer},
+    statistic_printer::StatisticPrinter,
+};
+use owo_colors::OwoColorize;
+use std::collections::HashMap;
+
+// Number of the most common error messages printed by default
+const DEFAULT_TOP_ERRORS: usize = 10;
+
+/// Number of the successful and failed calls of the particular function
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CallOutcomes {
+    pub success: u64,
+    pub failure: u64,
+}
+
+impl CallOutcomes {
+    /// Share of the failed calls in percents
+    pub fn error_rate(&self) -> f64 {
+        match self.success + self.failure {
+            0 => 0.,
+            total => self.failure as f64 / total as f64 * 100.,
+        }
+    }
+}
+
+/// Structure to store the success and failure counts per each function along with the error messages.
+/// It should be used with the batch in the continue-on-error mode, which records the failed calls into the statistic
+/// * `errors` - number of the failures per (function, message), the panic message is extracted from the contract errors
+#[derive(Debug)]
+pub struct ErrorRate {
+    pub func_outcomes: HashMap<String, CallOutcomes>,
+    pub errors: HashMap<(String, String), u64>,
+    top_errors: usize,
+    mode_printer: ModePrinter,
+    report_format: ReportFormat,
+}
+
+impl ErrorRate {
+    pub fn new(mode_printer: ModePrinter) -> Self {
+        Self {
+            func_outcomes: HashMap::new(),
+            errors: HashMap::new(),
+            top_errors: DEFAULT_TOP_ERRORS,
+            mode_printer,
+            report_format: ReportFormat::default(),
+        }
+    }
+
+    pub fn with_report_format(mut self, report_format: ReportFormat) -> Self {
+        self.report_format = report_format;
+        self
+    }
+
+    /// Set the number of the most common error messages in the report
+    pub fn with_top_errors(mut self, top_errors: usize) -> Self {
+        self.top_errors = top_errors;
+        self
+    }
+
+    /// The most common errors sorted by the number of occurrences
+    pub fn top_errors(&self) -> Vec<(&(String, String), u64)> {
+        let mut errors: Vec<_> = self
+            .errors
+            .iter()
+            .map(|(key, count)| (key, *count))
+            .collect();
+        errors.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        errors.truncate(self.top_errors);
+        errors
+    }
+}
+
+impl Default for ErrorRate {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+/// Extract the panic message of the smart-contract from the error, the whole error is returned otherwise
+/// * Note: call failures contain `Smart contract panicked: <message>`, view failures contain `panic_msg: "<message>"`,
+///   which could be escaped in the debug output of the error
+pub fn panic_message(error: &str) -> String {
+    const PANIC_PREFIXES: [&str; 3] = [
+        "Smart contract panicked: ",
+        "panic_msg: \"",
+        "panic_msg: \\\"",
+    ];
+
+    PANIC_PREFIXES
+        .iter()
+        .find_map(|prefix| error.split_once(prefix))
+        .and_then(|(_, message)| message.split(['"', '\\', '\n']).next())
+        .map(|message| message.trim_end_matches(')').to_owned())
+        .unwrap_or_else(|| error.to_owned())
+}
+
+impl StatisticConsumer for ErrorRate {
+    fn consume_statistic(&mut self, stat: &Statistic) {
+        let outcomes = self
+            .func_outcomes
+            .entry(stat.func_name.clone())
+            .or_default();
+        match &stat.error {
+            Some(error) => {
+                outcomes.failure += 1;
+                *self
+                    .errors
+                    .entry((stat.func_name.clone(), panic_message(error)))
+                    .or_insert(0) += 1;
+            }
+            None => outcomes.success += 1,
+        }
+    }
+
+    fn clean_statistic(&mut self) {
+        self.func_outcomes.clear();
+        self.errors.clear();
+    }
+}
+
+impl StatisticPrinter for ErrorRate {
+    fn get_printer_mode(&self) -> &ModePrinter {
+        &self.mode_printer
+    }
+
+    fn get_report_format(&self) -> ReportFormat {
+        self.report_format
+    }
+
+    fn report_tables(&self) -> Vec<ReportTable> {
+        let mut outcomes_vec: Vec<_> = self.func_outcomes.iter().collect();
+        outcomes_vec.sort_by(|a, b| b.1.failure.cmp(&a.1.failure).then_with(|| a.0.cmp(b.0)));
+
+        let mut table = ReportTable::new(
+            "Error rate",
+            ["Function", "Success", "Failure", "Error rate"]
+                .iter()
+                .map(|title| title.to_string())
+                .collect(),
+        );
+        for (func_name, outcomes) in outcomes_vec {
+            let error_rate = outcomes.error_rate();
+            table.add_row(vec![
+                ReportCell::new(func_name.green().bold(), func_name.as_str()),
+                ReportCell::new(outcomes.success.blue(), outcomes.success),
+                ReportCell::new(outcomes.failure.red(), outcomes.failure),
+                ReportCell::new(
+                    format!("{:.2}%", error_rate).bright_magenta().bold(),
+                    (error_rate * 100.).round() / 100.,
+                ),
+            ]);
+        }
+        let mut tables = vec![table];
+
+        if !self.errors.is_empty() {
+            let mut table = ReportTable::new(
+                "Most common errors",
+                ["Function", "Error", "Count"]
+                    .iter()
+                    .map(|title| title.to_string())
+                    .collect(),
+            );
+            for ((func_name, message), count) in self.top_errors() {
+                table.add_row(vec![
+                    ReportCell::new(func_name.green().bold(), func_name.as_str()),
+                    ReportCell::new(message.red(), message.as_str()),
+                    ReportCell::new(count.blue(), count),
+                ]);
+            }
+            tables.push(table);
+        }
+
+        tables
+    }
+}
@@ -0,0 +1,37 @@
+use super::{global_registry::GlobalRegistry, statistic_consumer::Statistic};
+use crate::error::Result;
+use std::{cell::RefCell, future::Future};
+
+tokio::task_local! {
+    // statistic of the last failed call made by the operation which is run by `capture_failed_call`
+    static FAILED_CALL: RefCell<Option<Statistic>>;
+}
+
+/// Record the statistic of the failed call, so the batch which runs the call knows its function name,
+/// call info and outcomes, while the error of the call is returned unchanged.
+/// It is called by the generated functions. The statistic is recorded by the global registry if it is enabled,
+/// otherwise it is ignored outside of `capture_failed_call`
+pub fn record_failed_call(stat: Statistic) {
+    if GlobalRegistry::is_enabled() {
+        GlobalRegistry::record(&stat);
+    }
+    let _ = FAILED_CALL.try_with(|failed_call| *failed_call.borrow_mut() = Some(stat));
+}
+
+/// Run the operation and capture the statistic of the failed call made by it.
+/// The statistic is returned only if the operation failed, the concurrent operations capture their calls separately
+/// * Note: in case the operation makes several calls, the last failed one is returned
+pub async fn capture_failed_call<T>(
+    op: impl Future<Output = Result<T>>,
+) -> (Result<T>, Option<Statistic>) {
+    FAILED_CALL
+        .scope(RefCell::new(None), async {
+            let res = op.await;
+            let failed_call = FAILED_CALL.with(|failed_call| failed_call.borrow_mut().take());
+            match res {
+                Ok(value) => (Ok(value), None),
+                Err(err) => (Err(err), failed_call),
+            }
+        })
+        .await
+}
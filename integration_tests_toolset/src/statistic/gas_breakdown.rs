@@ -0,0 +1,218 @@
+use super::{
+    gas_usage_aggregator::GasPrinter,
+    mode_printer::ModePrinter,
+    numeric_aggregator::{
+        NumericAggregator, NumericStatistic, OperationValues, StatisticColumn, DEFAULT_COLUMNS,
+    },
+    report::{ReportCell, ReportFormat, ReportTable},
+    statistic_consumer::{Statistic, StatisticConsumer},
+    statistic_printer::StatisticPrinter,
+};
+use crate::tx_result::TxResultDetails;
+use owo_colors::OwoColorize;
+use std::collections::{BTreeMap, HashMap};
+use workspaces::{
+    types::{Balance, Gas},
+    AccountId,
+};
+
+/// Receipt of the function call: (function, receipt depth, executor, method)
+pub type ReceiptKey = (String, usize, AccountId, Option<String>);
+
+/// Struct for representing the gas usage of every receipt of the function calls.
+/// Gas is attributed to the account which executed the receipt and the method it called, so it shows
+/// which contract dominates the cost of the cross-contract flow
+/// * `receipt_gas` - gas per (function, receipt depth, executor, method), the depth 0 is the transaction itself
+/// * `contract_gas` - gas per (function, executor) summed over all receipts of the call
+///
+/// * Note: the method names are known only for the calls measured by `TxResult::measure_receipt_methods`,
+///   the other receipts (and the refunds) have no method
+#[derive(Debug)]
+pub struct GasBreakdown {
+    pub receipt_gas: NumericAggregator<ReceiptKey, Gas>,
+    pub receipt_cost: NumericAggregator<ReceiptKey, Balance>,
+    pub contract_gas: NumericAggregator<(String, AccountId), Gas>,
+    pub contract_cost: NumericAggregator<(String, AccountId), Balance>,
+    // total gas of the function and its contracts over all calls, it is used for the share of the contract
+    func_total: HashMap<String, u128>,
+    contract_total: HashMap<(String, AccountId), u128>,
+    columns: Vec<StatisticColumn>,
+    mode_printer: ModePrinter,
+    report_format: ReportFormat,
+}
+
+impl GasBreakdown {
+    /// * `columns` - statistical values which should be printed in the report
+    pub fn new(mode_printer: ModePrinter, columns: &[StatisticColumn]) -> Self {
+        Self {
+            receipt_gas: NumericAggregator::default(),
+            receipt_cost: NumericAggregator::default(),
+            contract_gas: NumericAggregator::default(),
+            contract_cost: NumericAggregator::default(),
+            func_total: HashMap::new(),
+            contract_total: HashMap::new(),
+            columns: columns.to_vec(),
+            mode_printer,
+            report_format: ReportFormat::default(),
+        }
+    }
+
+    pub fn with_report_format(mut self, report_format: ReportFormat) -> Self {
+        self.report_format = report_format;
+        self
+    }
+
+    /// Share of the contract in the total gas usage of the function, in percents
+    pub fn contract_share(&self, func_name: &str, account_id: &AccountId) -> f64 {
+        let func_total = self.func_total.get(func_name).cloned().unwrap_or_default();
+        let contract_total = self
+            .contract_total
+            .get(&(func_name.to_owned(), account_id.clone()))
+            .cloned()
+            .unwrap_or_default();
+
+        match func_total {
+            0 => 0.,
+            _ => contract_total as f64 / func_total as f64 * 100.,
+        }
+    }
+
+    fn header(&self, keys: &[&str]) -> Vec<String> {
+        let mut header: Vec<_> = keys.iter().map(|key| key.to_string()).collect();
+        header.push("Count".to_owned());
+        header.extend(self.columns.iter().map(|column| column.title().to_owned()));
+        header
+    }
+
+    fn values(
+        &self,
+        gas_stat: &NumericStatistic<Gas>,
+        cost_stat: Option<NumericStatistic<Balance>>,
+    ) -> Vec<ReportCell> {
+        let cost_stat = cost_stat
+            .unwrap_or_else(|| NumericStatistic::from(&OperationValues::<Balance>::default()));
+
+        let mut cells = vec![ReportCell::new(
+            gas_stat.count.blue().bold(),
+            gas_stat.count,
+        )];
+        cells.extend(
+            self.columns
+                .iter()
+                .map(|column| gas_stat.value(*column).gas_cell(cost_stat.value(*column))),
+        );
+        cells
+    }
+}
+
+impl Default for GasBreakdown {
+    fn default() -> Self {
+        Self::new(Default::default(), &DEFAULT_COLUMNS)
+    }
+}
+
+impl StatisticConsumer for GasBreakdown {
+    fn consume_statistic(&mut self, stat: &Statistic) {
+        let (TxResultDetails::Call(call_data), false) = (&stat.details, stat.is_failed()) else {
+            return;
+        };
+
+        // receipts of the same executor, method and depth are summed up within the call, e.g. refunds
+        let mut receipts: BTreeMap<(usize, AccountId, Option<String>), (Gas, Balance)> =
+            BTreeMap::new();
+        let mut contracts: BTreeMap<AccountId, (Gas, Balance)> = BTreeMap::new();
+        for (outcome, depth) in call_data.outcome_depths() {
+            for (gas, cost) in [
+                receipts
+                    .entry((
+                        depth,
+                        outcome.executor_id.clone(),
+                        outcome.method_name.clone(),
+                    ))
+                    .or_default(),
+                contracts.entry(outcome.executor_id.clone()).or_default(),
+            ] {
+                *gas += outcome.gas_burnt;
+                *cost += outcome.tokens_burnt;
+            }
+        }
+
+        let func = &stat.func_name;
+        for ((depth, account_id, method), (gas, cost)) in receipts {
+            let key = (func.clone(), depth, account_id, method);
+            self.receipt_gas.push(key.clone(), gas);
+            self.receipt_cost.push(key, cost);
+        }
+        for (account_id, (gas, cost)) in contracts {
+            let key = (func.clone(), account_id);
+            self.contract_gas.push(key.clone(), gas);
+            self.contract_cost.push(key.clone(), cost);
+            *self.contract_total.entry(key).or_default() += gas as u128;
+            *self.func_total.entry(func.clone()).or_default() += gas as u128;
+        }
+    }
+
+    fn clean_statistic(&mut self) {
+        self.receipt_gas.clear();
+        self.receipt_cost.clear();
+        self.contract_gas.clear();
+        self.contract_cost.clear();
+        self.func_total.clear();
+        self.contract_total.clear();
+    }
+}
+
+impl StatisticPrinter for GasBreakdown {
+    fn get_printer_mode(&self) -> &ModePrinter {
+        &self.mode_printer
+    }
+
+    fn get_report_format(&self) -> ReportFormat {
+        self.report_format
+    }
+
+    fn report_tables(&self) -> Vec<ReportTable> {
+        let mut receipt_stat_vec = self.receipt_gas.statistics();
+        receipt_stat_vec.sort_by(|a, b| a.0.cmp(b.0));
+
+        let mut receipt_table = ReportTable::new(
+            "Gas usage by receipt",
+            self.header(&["Function", "Depth", "Executor", "Method"]),
+        );
+        for (key, gas_stat) in receipt_stat_vec.iter() {
+            let (func, depth, account_id, method) = key;
+            let method = method.as_deref().unwrap_or("-");
+            let mut cells = vec![
+                ReportCell::new(func.green().bold(), func.as_str()),
+                ReportCell::new(depth.blue(), *depth),
+                ReportCell::new(account_id.as_str().cyan().bold(), account_id.as_str()),
+                ReportCell::new(method.yellow(), method),
+            ];
+            cells.extend(self.values(gas_stat, self.receipt_cost.statistic(key)));
+            receipt_table.add_row(cells);
+        }
+
+        let mut contract_stat_vec = self.contract_gas.statistics();
+        contract_stat_vec.sort_by(|a, b| a.0.cmp(b.0));
+
+        let mut header = self.header(&["Function", "Contract"]);
+        header.push("Share".to_owned());
+        let mut contract_table = ReportTable::new("Gas usage by contract", header);
+        for (key, gas_stat) in contract_stat_vec.iter() {
+            let (func, account_id) = key;
+            let share = self.contract_share(func, account_id);
+            let mut cells = vec![
+                ReportCell::new(func.green().bold(), func.as_str()),
+                ReportCell::new(account_id.as_str().cyan().bold(), account_id.as_str()),
+            ];
+            cells.extend(self.values(gas_stat, self.contract_cost.statistic(key)));
+            cells.push(ReportCell::new(
+                format!("{:.2}%", share).bright_magenta().bold(),
+                (share * 100.).round() / 100.,
+            ));
+            contract_table.add_row(cells);
+        }
+
+        vec![receipt_table, contract_table]
+    }
+}
@@ -1,114 +1,126 @@
 use super::{
+    baseline::{BaselineComparator, BaselineEntry},
+    group_by::GroupBy,
     mode_printer::ModePrinter,
+    numeric_aggregator::{
+        NumericAggregator, NumericStatistic, OperationValues, StatisticColumn, DEFAULT_COLUMNS,
+    },
+    report::{ReportCell, ReportFormat, ReportTable},
     statistic_consumer::{Statistic, StatisticConsumer},
     statistic_printer::StatisticPrinter,
 };
 use crate::tx_result::TxResultDetails;
 use owo_colors::OwoColorize;
-use prettytable::{row, Table};
-use std::collections::{BinaryHeap, HashMap};
-use workspaces::types::Gas;
+use serde_json::json;
+use std::collections::BTreeMap;
+use workspaces::types::{Balance, Gas};
 
-/// Struct for representing the range of values which shows the gas usage of the particular operation.
-/// It should be used in scenarios with multiple gas measurements for the same operation.
-#[derive(Debug)]
-pub struct OperationGasUsage {
-    pub heap: BinaryHeap<Gas>,
-}
-
-/// Struct for representing gas statistical values
-#[derive(Debug)]
-pub struct OperationGasStatistic {
-    pub min: Gas,
-    pub max: Gas,
-    pub median: Gas,
-}
+/// Range of values which shows the gas usage of the particular operation
+pub type OperationGasUsage = OperationValues<Gas>;
 
-impl From<&OperationGasUsage> for OperationGasStatistic {
-    fn from(op_gas: &OperationGasUsage) -> Self {
-        let gas_vec: Vec<Gas> = op_gas.heap.clone().into_sorted_vec();
-        if gas_vec.is_empty() {
-            return Self {
-                min: 0,
-                max: 0,
-                median: 0,
-            };
-        } else {
-            return Self {
-                min: gas_vec.first().cloned().unwrap_or_default(),
-                max: gas_vec.last().cloned().unwrap_or_default(),
-                median: {
-                    let mid = gas_vec.len() / 2;
-                    if gas_vec.len() % 2 == 0 {
-                        (gas_vec[mid] + gas_vec[mid - 1]) / 2
-                    } else {
-                        gas_vec[mid]
-                    }
-                },
-            };
-        }
-    }
-}
+/// Gas statistical values of the particular operation
+pub type OperationGasStatistic = NumericStatistic<Gas>;
 
 // Struct which represents gas usage per each function
+// By default the cost of the gas is the actual amount of burnt tokens,
+// with `with_gas_price` the cost is calculated from the gas usage with the fixed price
+// The statistic could be grouped by the labels of the operations instead of the function with `with_group_by`
 #[derive(Debug)]
 pub struct GasUsage {
-    pub func_gas: HashMap<String, OperationGasUsage>,
+    pub func_gas: NumericAggregator<String, Gas>,
+    // NEAR spent for the gas per each function
+    pub func_cost: NumericAggregator<String, Balance>,
+    gas_price: Option<Balance>,
+    group_by: GroupBy,
+    columns: Vec<StatisticColumn>,
     mode_printer: ModePrinter,
+    report_format: ReportFormat,
 }
 
 impl GasUsage {
-    pub fn new(mode_printer: ModePrinter) -> Self {
+    /// * `columns` - statistical values which should be printed in the report
+    pub fn new(mode_printer: ModePrinter, columns: &[StatisticColumn]) -> Self {
         Self {
-            func_gas: HashMap::new(),
+            func_gas: NumericAggregator::default(),
+            func_cost: NumericAggregator::default(),
+            gas_price: None,
+            group_by: GroupBy::default(),
+            columns: columns.to_vec(),
             mode_printer,
+            report_format: ReportFormat::default(),
         }
     }
+
+    pub fn with_report_format(mut self, report_format: ReportFormat) -> Self {
+        self.report_format = report_format;
+        self
+    }
+
+    /// Calculate the cost of the gas with the price of the single gas unit in yoctoNEAR
+    pub fn with_gas_price(mut self, gas_price: Balance) -> Self {
+        self.gas_price = Some(gas_price);
+        self
+    }
+
+    /// Group the statistic by the function, the label of the operation or both
+    pub fn with_group_by(mut self, group_by: GroupBy) -> Self {
+        self.group_by = group_by;
+        self
+    }
 }
 
 impl Default for GasUsage {
     fn default() -> Self {
-        Self {
-            func_gas: HashMap::new(),
-            mode_printer: Default::default(),
-        }
+        Self::new(Default::default(), &DEFAULT_COLUMNS)
     }
 }
 
 /// Interface for printing gas usage
-trait GasPrinter {
-    fn print_gas(&self) -> String;
+pub(crate) trait GasPrinter {
+    fn print_gas(&self, cost: Balance) -> String;
+
+    fn gas_cell(&self, cost: Balance) -> ReportCell;
 }
 
 impl GasPrinter for Gas {
-    fn print_gas(&self) -> String {
+    fn print_gas(&self, cost: Balance) -> String {
         format!(
             "{:.3} {} ({:.6} {})",
             (*self as f64 / 1_000_000_000_000.).bright_magenta().bold(),
             "Tgas",
-            (*self as f64 / 10_000_000_000_000_000.)
+            (cost as f64 / near_sdk::ONE_NEAR as f64)
                 .bright_magenta()
                 .bold(),
             "NEAR"
         )
     }
+
+    fn gas_cell(&self, cost: Balance) -> ReportCell {
+        ReportCell::new(
+            self.print_gas(cost),
+            json!({ "gas": self, "near": cost as f64 / near_sdk::ONE_NEAR as f64 }),
+        )
+    }
 }
 
 impl StatisticConsumer for GasUsage {
     fn consume_statistic(&mut self, stat: &Statistic) {
-        if let TxResultDetails::Call(call_data) = &stat.details {
-            let op_gas = self
-                .func_gas
-                .entry(stat.func_name.clone())
-                .or_insert_with(|| OperationGasUsage {
-                    heap: BinaryHeap::new(),
-                });
-            op_gas.heap.push(call_data.gas);
+        if let (TxResultDetails::Call(call_data), false) = (&stat.details, stat.is_failed()) {
+            let key = self.group_by.key(stat);
+            self.func_gas.push(key.clone(), call_data.gas);
+            self.func_cost.push(
+                key,
+                match self.gas_price {
+                    Some(gas_price) => call_data.gas as Balance * gas_price,
+                    None => call_data.tokens_burnt(),
+                },
+            );
         }
     }
 
     fn clean_statistic(&mut self) {
         self.func_gas.clear();
+        self.func_cost.clear();
     }
 }
 
@@ -117,35 +129,46 @@ impl StatisticPrinter for GasUsage {
         &self.mode_printer
     }
 
-    fn make_report(&self) -> String {
-        let mut gas_stat_vec: Vec<_> = self
-            .func_gas
-            .iter()
-            .map(|(func, gas)| {
-                let gas_stat = OperationGasStatistic::from(gas);
-                (
-                    func,
-                    gas.heap.len(),
-                    gas_stat.min,
-                    gas_stat.median,
-                    gas_stat.max,
-                )
-            })
-            .collect();
-
-        gas_stat_vec.sort_by(|a, b| b.3.cmp(&a.3));
-
-        let mut table = Table::new();
-        table.add_row(row!["Function", "Count", "Min", "Median", "Max"]);
-        for (func, count, min, median, max) in gas_stat_vec.iter() {
-            table.add_row(row![
-                func.green().bold(),
-                count.to_string().blue().bold(),
-                min.print_gas(),
-                median.print_gas(),
-                max.print_gas()
-            ]);
+    fn get_report_format(&self) -> ReportFormat {
+        self.report_format
+    }
+
+    fn report_tables(&self) -> Vec<ReportTable> {
+        let mut header = vec![self.group_by.title().to_owned(), "Count".to_owned()];
+        header.extend(self.columns.iter().map(|column| column.title().to_owned()));
+        let mut table = ReportTable::new("Gas usage", header);
+
+        for (func, gas_stat) in self.func_gas.statistics() {
+            let cost_stat = self
+                .func_cost
+                .statistic(func)
+                .unwrap_or_else(|| NumericStatistic::from(&OperationValues::<Balance>::default()));
+
+            let mut cells = vec![
+                ReportCell::new(func.green().bold(), func.as_str()),
+                ReportCell::new(gas_stat.count.blue().bold(), gas_stat.count),
+            ];
+            cells.extend(
+                self.columns
+                    .iter()
+                    .map(|column| gas_stat.value(*column).gas_cell(cost_stat.value(*column))),
+            );
+            table.add_row(cells);
         }
-        format!("{}\n{}", "Gas usage".bright_yellow().bold(), table)
+        vec![table]
+    }
+}
+
+impl BaselineComparator for GasUsage {
+    fn baseline_section(&self) -> &'static str {
+        "gas"
+    }
+
+    fn baseline_entries(&self) -> BTreeMap<String, BaselineEntry> {
+        self.func_gas
+            .statistics()
+            .into_iter()
+            .map(|(func, stat)| (func.clone(), BaselineEntry::from(&stat)))
+            .collect()
     }
 }
@@ -0,0 +1,120 @@
+use super::{
+    combined_report::CombinedReport,
+    mode_printer::ModePrinter,
+    report::ReportFormat,
+    statistic_consumer::{Statistic, StatisticConsumer},
+};
+use crate::error::Result;
+use std::sync::{Mutex, MutexGuard, Once};
+
+static REGISTRY: Mutex<Option<GlobalRegistry>> = Mutex::new(None);
+static AT_EXIT: Once = Once::new();
+
+/// Suite-wide registry of the statistic consumers, which is populated by every generated call automatically.
+/// It is opt-in: the registry is enabled with `install`, usually at the beginning of every test, since the order
+/// of the tests is not defined. The first installed registry is used, so the statistic is merged across the tests.
+/// The summary (combined report of all consumers) is printed once when the test process exits,
+/// or earlier on the explicit `finish`, e.g. in the `main` of the test binary with `harness = false`.
+/// * Note: the registry is global for the process, so the statistic is merged across the tests only with the default
+///   libtest runner, which runs all the tests of the binary in one process. Under `cargo nextest` every test is run
+///   in its own process, so the summary is printed per test.
+/// * Note: while the registry is installed, it records the calls of all the tests which run concurrently
+/// * Note: the failed calls are recorded with their error, e.g. for the suite-wide `ErrorRate`
+#[derive(Debug)]
+pub struct GlobalRegistry {
+    run_name: String,
+    consumers: Vec<Box<dyn StatisticConsumer>>,
+    mode_printer: ModePrinter,
+    report_format: ReportFormat,
+}
+
+impl GlobalRegistry {
+    pub fn new(run_name: &str) -> Self {
+        Self {
+            run_name: run_name.to_owned(),
+            consumers: vec![],
+            mode_printer: ModePrinter::default(),
+            report_format: ReportFormat::default(),
+        }
+    }
+
+    pub fn with_consumer(mut self, consumer: impl StatisticConsumer + 'static) -> Self {
+        self.consumers.push(Box::new(consumer));
+        self
+    }
+
+    /// Destination of the summary, e.g. the file for the CI artifacts
+    pub fn with_mode_printer(mut self, mode_printer: ModePrinter) -> Self {
+        self.mode_printer = mode_printer;
+        self
+    }
+
+    pub fn with_report_format(mut self, report_format: ReportFormat) -> Self {
+        self.report_format = report_format;
+        self
+    }
+
+    /// Enable the registry, in case it is already enabled the call is ignored.
+    /// Returns whether the registry was installed by this call
+    pub fn install(self) -> bool {
+        let mut registry = lock();
+        if registry.is_some() {
+            return false;
+        }
+        *registry = Some(self);
+
+        AT_EXIT.call_once(|| unsafe {
+            libc::atexit(finish_at_exit);
+        });
+        true
+    }
+
+    pub fn is_enabled() -> bool {
+        lock().is_some()
+    }
+
+    /// Populate the consumers of the registry, the statistic is ignored if the registry is not enabled
+    pub fn record(stat: &Statistic) {
+        if let Some(registry) = lock().as_mut() {
+            for consumer in registry.consumers.iter_mut() {
+                consumer.consume_statistic(stat);
+            }
+        }
+    }
+
+    /// Combined report of the consumers of the registry at the moment
+    pub fn combined_report() -> Option<CombinedReport> {
+        lock().as_ref().map(GlobalRegistry::make_combined_report)
+    }
+
+    /// Print the summary and disable the registry, so it is not printed again at the exit.
+    /// The call is ignored if the registry is not enabled, the registry could be installed again afterwards
+    pub fn finish() -> Result<()> {
+        let Some(registry) = lock().take() else {
+            return Ok(());
+        };
+
+        let report = registry.make_combined_report().render();
+        registry.mode_printer.print(report.as_bytes())
+    }
+
+    fn make_combined_report(&self) -> CombinedReport {
+        let mut report = CombinedReport::new(&self.run_name).with_report_format(self.report_format);
+        for consumer in self.consumers.iter() {
+            report.add_consumer(consumer);
+        }
+        report
+    }
+}
+
+// The statistic is still valid even if some test panicked during the consumption
+fn lock() -> MutexGuard<'static, Option<GlobalRegistry>> {
+    REGISTRY
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+extern "C" fn finish_at_exit() {
+    // there is no way to report the error at the exit
+    let _ = GlobalRegistry::finish();
+}
@@ -0,0 +1,41 @@
+use super::statistic_consumer::Statistic;
+
+/// Label of the calls without the labels, when the statistic is grouped by the label
+pub const UNLABELED: &str = "<unlabeled>";
+
+/// Defines how the statistic is grouped in the reports
+/// * Function - by the function name (default)
+/// * Label - by the labels of the operations, e.g. the steps of the scenario
+/// * FunctionAndLabel - by the function name within every label, e.g. `ft_transfer [large]`
+///
+/// * Note: several labels of the same call are joined into the single group
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GroupBy {
+    #[default]
+    Function,
+    Label,
+    FunctionAndLabel,
+}
+
+impl GroupBy {
+    /// Group of the particular call
+    pub fn key(&self, stat: &Statistic) -> String {
+        match (self, stat.labels.is_empty()) {
+            (Self::Function, _) | (Self::FunctionAndLabel, true) => stat.func_name.clone(),
+            (Self::Label, true) => UNLABELED.to_owned(),
+            (Self::Label, false) => stat.labels.join(", "),
+            (Self::FunctionAndLabel, false) => {
+                format!("{} [{}]", stat.func_name, stat.labels.join(", "))
+            }
+        }
+    }
+
+    /// Title of the group column in the report
+    pub fn title(&self) -> &'static str {
+        match self {
+            Self::Function => "Function",
+            Self::Label => "Label",
+            Self::FunctionAndLabel => "Function [label]",
+        }
+    }
+}
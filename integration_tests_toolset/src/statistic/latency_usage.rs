@@ -0,0 +1,103 @@
+use super::{
+    mode_printer::ModePrinter,
+    numeric_aggregator::{NumericAggregator, StatisticColumn},
+    report::{ReportCell, ReportFormat, ReportTable},
+    statistic_consumer::{Statistic, StatisticConsumer},
+    statistic_printer::StatisticPrinter,
+};
+use owo_colors::OwoColorize;
+use std::time::Duration;
+
+/// Columns which are printed by default for the latency
+pub const DEFAULT_LATENCY_COLUMNS: [StatisticColumn; 4] = [
+    StatisticColumn::Min,
+    StatisticColumn::Median,
+    StatisticColumn::P95,
+    StatisticColumn::Max,
+];
+
+/// Struct for representing the wall-clock duration of the calls per each function.
+/// The duration includes the RPC retries and the waiting for the transaction execution,
+/// so it shows the latency of the calls under the particular load in the sandbox or testnet
+/// * Note: calls without the recorded timestamps are skipped
+#[derive(Debug)]
+pub struct LatencyUsage {
+    pub func_latency: NumericAggregator<String, Duration>,
+    columns: Vec<StatisticColumn>,
+    mode_printer: ModePrinter,
+    report_format: ReportFormat,
+}
+
+impl LatencyUsage {
+    /// * `columns` - statistical values which should be printed in the report
+    pub fn new(mode_printer: ModePrinter, columns: &[StatisticColumn]) -> Self {
+        Self {
+            func_latency: NumericAggregator::default(),
+            columns: columns.to_vec(),
+            mode_printer,
+            report_format: ReportFormat::default(),
+        }
+    }
+
+    pub fn with_report_format(mut self, report_format: ReportFormat) -> Self {
+        self.report_format = report_format;
+        self
+    }
+}
+
+impl Default for LatencyUsage {
+    fn default() -> Self {
+        Self::new(Default::default(), &DEFAULT_LATENCY_COLUMNS)
+    }
+}
+
+// The duration is printed in milliseconds
+fn latency_cell(duration: Duration) -> ReportCell {
+    let millis = duration.as_secs_f64() * 1_000.;
+    ReportCell::new(
+        format!("{:.3} {}", millis.bright_magenta().bold(), "ms"),
+        (millis * 1_000.).round() / 1_000.,
+    )
+}
+
+impl StatisticConsumer for LatencyUsage {
+    fn consume_statistic(&mut self, stat: &Statistic) {
+        if let Some(duration) = stat.duration() {
+            self.func_latency.push(stat.func_name.clone(), duration);
+        }
+    }
+
+    fn clean_statistic(&mut self) {
+        self.func_latency.clear();
+    }
+}
+
+impl StatisticPrinter for LatencyUsage {
+    fn get_printer_mode(&self) -> &ModePrinter {
+        &self.mode_printer
+    }
+
+    fn get_report_format(&self) -> ReportFormat {
+        self.report_format
+    }
+
+    fn report_tables(&self) -> Vec<ReportTable> {
+        let mut header = vec!["Function".to_owned(), "Count".to_owned()];
+        header.extend(self.columns.iter().map(|column| column.title().to_owned()));
+        let mut table = ReportTable::new("Latency", header);
+
+        for (func, latency_stat) in self.func_latency.statistics() {
+            let mut cells = vec![
+                ReportCell::new(func.green().bold(), func.as_str()),
+                ReportCell::new(latency_stat.count.blue().bold(), latency_stat.count),
+            ];
+            cells.extend(
+                self.columns
+                    .iter()
+                    .map(|column| latency_cell(latency_stat.value(*column))),
+            );
+            table.add_row(cells);
+        }
+        vec![table]
+    }
+}
@@ -1,8 +1,24 @@
+pub mod baseline;
+pub mod budget;
 pub mod call_counter;
+pub mod combined_report;
+pub mod error_rate;
+pub mod failed_call;
+pub mod gas_breakdown;
 pub mod gas_usage_aggregator;
+pub mod global_registry;
+pub mod group_by;
+pub mod latency_usage;
 pub mod mode_printer;
+pub mod numeric_aggregator;
+pub mod receipt_usage;
+pub mod report;
+pub mod shared_consumer;
+pub mod snapshot;
 pub mod statistic_consumer;
 pub mod statistic_group_ext;
 pub mod statistic_group_printer;
 pub mod statistic_printer;
+pub mod statistic_trace;
 pub mod storage_usage_aggregator;
+pub mod timeline;
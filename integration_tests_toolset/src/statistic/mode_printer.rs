@@ -1,36 +1,81 @@
+use super::report::{render_report, strip_ansi, ReportFormat, ReportTable};
 use crate::error::TestError;
 use std::{
-    fs::File,
-    io::{self, Write},
+    fs::{File, OpenOptions},
+    io::{self, IsTerminal, Write},
     path::PathBuf,
 };
 
 /// Defines the output destination for printing the statistic
 /// * Console - will print to the Console only
-/// * File - will print to the specified file
+/// * File - will print to the specified file, the previous content of the file is truncated
 /// * Mixed - will print both to the Console and specified file
-#[derive(Debug)]
+/// * AppendFile - will append to the specified file, so several consumers could share it
+/// * AppendMixed - will print to the Console and append to the specified file
+///
+/// * Note: colour codes are stripped for the file and non-terminal outputs
+#[derive(Debug, Clone)]
 pub enum ModePrinter {
     Console,
     File(PathBuf),
     Mixed(PathBuf),
+    AppendFile(PathBuf),
+    AppendMixed(PathBuf),
 }
 
 impl ModePrinter {
     pub fn print(&self, buf: &[u8]) -> Result<(), TestError> {
+        let plain_buf = strip_ansi(&String::from_utf8_lossy(buf)).into_bytes();
+        let stdout_buf = if io::stdout().is_terminal() {
+            buf
+        } else {
+            &plain_buf
+        };
+
+        self.write(stdout_buf, &plain_buf)
+    }
+
+    /// Render and print the report, the colourized table is printed only to the terminal
+    pub fn print_report(
+        &self,
+        tables: &[ReportTable],
+        format: ReportFormat,
+    ) -> Result<(), TestError> {
+        let plain = render_report(tables, format, false);
+        let stdout_report = if io::stdout().is_terminal() {
+            render_report(tables, format, true)
+        } else {
+            plain.clone()
+        };
+
+        self.write(stdout_report.as_bytes(), plain.as_bytes())
+    }
+
+    fn write(&self, stdout_buf: &[u8], file_buf: &[u8]) -> Result<(), TestError> {
+        if let Some(mut file) = self.select_file_output()? {
+            file.write_all(file_buf)
+                .map_err(|err| TestError::Custom(err.to_string()))?;
+        }
+
         match self {
-            Self::Console => Self::select_stdout().write_all(buf),
-            Self::File(path) => Self::select_file_output(path)?.write_all(buf),
-            Self::Mixed(path) => Self::select_file_output(path)?
-                .write_all(buf)
-                .and_then(|_| Self::select_stdout().write_all(buf)),
+            Self::Console | Self::Mixed(_) | Self::AppendMixed(_) => {
+                Self::select_stdout().write_all(stdout_buf)
+            }
+            Self::File(_) | Self::AppendFile(_) => Ok(()),
         }
         .map_err(|err| TestError::Custom(err.to_string()))
     }
 
-    fn select_file_output(path: &PathBuf) -> Result<Box<dyn Write>, TestError> {
-        File::create(path)
-            .map(|f| Box::new(f) as Box<dyn Write>)
+    fn select_file_output(&self) -> Result<Option<Box<dyn Write>>, TestError> {
+        let file = match self {
+            Self::Console => return Ok(None),
+            Self::File(path) | Self::Mixed(path) => File::create(path),
+            Self::AppendFile(path) | Self::AppendMixed(path) => {
+                OpenOptions::new().create(true).append(true).open(path)
+            }
+        };
+
+        file.map(|f| Some(Box::new(f) as Box<dyn Write>))
             .map_err(|err| TestError::Custom(err.to_string()))
     }
 
@@ -0,0 +1,225 @@
+use std::{
+    collections::{BinaryHeap, HashMap},
+    fmt::Debug,
+    hash::Hash,
+    time::Duration,
+};
+
+/// Interface of the numeric values which could be aggregated, like gas, storage usage, NEAR amounts, etc.
+pub trait NumericValue: Copy + Ord + Default + Debug {
+    fn to_f64(self) -> f64;
+    fn from_f64(value: f64) -> Self;
+    // Mean of the two values, it is used for the median of the even number of values
+    fn midpoint(self, other: Self) -> Self;
+}
+
+macro_rules! impl_numeric_value {
+    ($($ty:ty),*) => {
+        $(
+            impl NumericValue for $ty {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn from_f64(value: f64) -> Self {
+                    value.round() as $ty
+                }
+
+                fn midpoint(self, other: Self) -> Self {
+                    self / 2 + other / 2 + (self % 2 + other % 2) / 2
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric_value!(u64, i64, u128);
+
+// Durations are aggregated in seconds
+impl NumericValue for Duration {
+    fn to_f64(self) -> f64 {
+        self.as_secs_f64()
+    }
+
+    fn from_f64(value: f64) -> Self {
+        Duration::from_secs_f64(value.max(0.))
+    }
+
+    fn midpoint(self, other: Self) -> Self {
+        (self + other) / 2
+    }
+}
+
+/// Statistical values which could be selected as the columns of the report
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatisticColumn {
+    Min,
+    Median,
+    Max,
+    Mean,
+    StdDev,
+    P90,
+    P95,
+    P99,
+}
+
+/// Columns which are printed by default
+pub const DEFAULT_COLUMNS: [StatisticColumn; 3] = [
+    StatisticColumn::Min,
+    StatisticColumn::Median,
+    StatisticColumn::Max,
+];
+
+impl StatisticColumn {
+    pub fn title(&self) -> &'static str {
+        match self {
+            Self::Min => "Min",
+            Self::Median => "Median",
+            Self::Max => "Max",
+            Self::Mean => "Mean",
+            Self::StdDev => "Std dev",
+            Self::P90 => "P90",
+            Self::P95 => "P95",
+            Self::P99 => "P99",
+        }
+    }
+}
+
+/// Struct for representing the range of values measured for the particular operation.
+/// It should be used in scenarios with multiple measurements for the same operation.
+#[derive(Debug, Clone)]
+pub struct OperationValues<V> {
+    pub heap: BinaryHeap<V>,
+}
+
+impl<V: Ord> Default for OperationValues<V> {
+    fn default() -> Self {
+        Self {
+            heap: BinaryHeap::new(),
+        }
+    }
+}
+
+/// Struct for representing statistical values of the operation
+/// * Note: percentiles are calculated with the nearest-rank method
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumericStatistic<V> {
+    pub count: usize,
+    pub min: V,
+    pub max: V,
+    pub median: V,
+    pub mean: f64,
+    pub std_dev: f64,
+    pub p90: V,
+    pub p95: V,
+    pub p99: V,
+}
+
+impl<V: NumericValue> NumericStatistic<V> {
+    /// Value of the particular column, mean and standard deviation are rounded to the value type
+    pub fn value(&self, column: StatisticColumn) -> V {
+        match column {
+            StatisticColumn::Min => self.min,
+            StatisticColumn::Median => self.median,
+            StatisticColumn::Max => self.max,
+            StatisticColumn::Mean => V::from_f64(self.mean),
+            StatisticColumn::StdDev => V::from_f64(self.std_dev),
+            StatisticColumn::P90 => self.p90,
+            StatisticColumn::P95 => self.p95,
+            StatisticColumn::P99 => self.p99,
+        }
+    }
+}
+
+impl<V: NumericValue> From<&OperationValues<V>> for NumericStatistic<V> {
+    fn from(op_values: &OperationValues<V>) -> Self {
+        let values: Vec<V> = op_values.heap.clone().into_sorted_vec();
+        if values.is_empty() {
+            return Self {
+                count: 0,
+                min: V::default(),
+                max: V::default(),
+                median: V::default(),
+                mean: 0.,
+                std_dev: 0.,
+                p90: V::default(),
+                p95: V::default(),
+                p99: V::default(),
+            };
+        }
+
+        let count = values.len();
+        let mid = count / 2;
+        let mean = values.iter().map(|value| value.to_f64()).sum::<f64>() / count as f64;
+        let variance = values
+            .iter()
+            .map(|value| (value.to_f64() - mean).powi(2))
+            .sum::<f64>()
+            / count as f64;
+        let percentile = |p: usize| values[((p * count + 99) / 100).max(1) - 1];
+
+        Self {
+            count,
+            min: values[0],
+            max: values[count - 1],
+            median: match count % 2 {
+                0 => values[mid - 1].midpoint(values[mid]),
+                _ => values[mid],
+            },
+            mean,
+            std_dev: variance.sqrt(),
+            p90: percentile(90),
+            p95: percentile(95),
+            p99: percentile(99),
+        }
+    }
+}
+
+/// Generic aggregator of the numeric values per each key (function name, account, etc.)
+#[derive(Debug)]
+pub struct NumericAggregator<K, V> {
+    pub values: HashMap<K, OperationValues<V>>,
+}
+
+impl<K, V> Default for NumericAggregator<K, V> {
+    fn default() -> Self {
+        Self {
+            values: HashMap::new(),
+        }
+    }
+}
+
+impl<K, V> NumericAggregator<K, V>
+where
+    K: Eq + Hash + Ord,
+    V: NumericValue,
+{
+    pub fn push(&mut self, key: K, value: V) {
+        self.values.entry(key).or_default().heap.push(value);
+    }
+
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn statistic(&self, key: &K) -> Option<NumericStatistic<V>> {
+        self.values.get(key).map(NumericStatistic::from)
+    }
+
+    /// Statistical values for every key sorted by the median in descending order,
+    /// the keys with the same median are sorted by the key, so the reports are stable between runs
+    pub fn statistics(&self) -> Vec<(&K, NumericStatistic<V>)> {
+        let mut statistics: Vec<_> = self
+            .values
+            .iter()
+            .map(|(key, values)| (key, NumericStatistic::from(values)))
+            .collect();
+
+        statistics.sort_by(|a, b| b.1.median.cmp(&a.1.median).then_with(|| a.0.cmp(b.0)));
+        statistics
+    }
+}
@@ -0,0 +1,120 @@
+use super::{
+    mode_printer::ModePrinter,
+    numeric_aggregator::{NumericAggregator, NumericStatistic, StatisticColumn},
+    report::{ReportCell, ReportFormat, ReportTable},
+    statistic_consumer::{Statistic, StatisticConsumer},
+    statistic_printer::StatisticPrinter,
+};
+use crate::tx_result::TxResultDetails;
+use owo_colors::OwoColorize;
+
+/// Columns which are printed by default for every receipt metric
+pub const DEFAULT_RECEIPT_COLUMNS: [StatisticColumn; 2] =
+    [StatisticColumn::Median, StatisticColumn::Max];
+
+/// Struct for representing the fan-out of the calls per each function
+/// * `func_receipts` - number of the receipts produced by the call, including the refunds
+/// * `func_contracts` - number of the distinct accounts which executed the transaction or its receipts
+/// * `func_depth` - depth of the receipt chain, the receipts created by the transaction have the depth 1
+#[derive(Debug)]
+pub struct ReceiptUsage {
+    pub func_receipts: NumericAggregator<String, u64>,
+    pub func_contracts: NumericAggregator<String, u64>,
+    pub func_depth: NumericAggregator<String, u64>,
+    columns: Vec<StatisticColumn>,
+    mode_printer: ModePrinter,
+    report_format: ReportFormat,
+}
+
+impl ReceiptUsage {
+    /// * `columns` - statistical values which should be printed in the report for every metric
+    pub fn new(mode_printer: ModePrinter, columns: &[StatisticColumn]) -> Self {
+        Self {
+            func_receipts: NumericAggregator::default(),
+            func_contracts: NumericAggregator::default(),
+            func_depth: NumericAggregator::default(),
+            columns: columns.to_vec(),
+            mode_printer,
+            report_format: ReportFormat::default(),
+        }
+    }
+
+    pub fn with_report_format(mut self, report_format: ReportFormat) -> Self {
+        self.report_format = report_format;
+        self
+    }
+
+    fn metrics(&self) -> [(&'static str, &NumericAggregator<String, u64>); 3] {
+        [
+            ("Receipts", &self.func_receipts),
+            ("Contracts", &self.func_contracts),
+            ("Depth", &self.func_depth),
+        ]
+    }
+}
+
+impl Default for ReceiptUsage {
+    fn default() -> Self {
+        Self::new(Default::default(), &DEFAULT_RECEIPT_COLUMNS)
+    }
+}
+
+impl StatisticConsumer for ReceiptUsage {
+    fn consume_statistic(&mut self, stat: &Statistic) {
+        if let (TxResultDetails::Call(call_data), false) = (&stat.details, stat.is_failed()) {
+            let func = &stat.func_name;
+            self.func_receipts
+                .push(func.clone(), call_data.receipt_outcomes.len() as u64);
+            self.func_contracts
+                .push(func.clone(), call_data.executor_ids().len() as u64);
+            self.func_depth
+                .push(func.clone(), call_data.receipt_depth() as u64);
+        }
+    }
+
+    fn clean_statistic(&mut self) {
+        self.func_receipts.clear();
+        self.func_contracts.clear();
+        self.func_depth.clear();
+    }
+}
+
+impl StatisticPrinter for ReceiptUsage {
+    fn get_printer_mode(&self) -> &ModePrinter {
+        &self.mode_printer
+    }
+
+    fn get_report_format(&self) -> ReportFormat {
+        self.report_format
+    }
+
+    fn report_tables(&self) -> Vec<ReportTable> {
+        let mut header = vec!["Function".to_owned(), "Count".to_owned()];
+        for (metric, _) in self.metrics() {
+            header.extend(
+                self.columns
+                    .iter()
+                    .map(|column| format!("{} {}", metric, column.title())),
+            );
+        }
+        let mut table = ReportTable::new("Receipts fan-out", header);
+
+        for (func, receipts_stat) in self.func_receipts.statistics() {
+            let mut cells = vec![
+                ReportCell::new(func.green().bold(), func.as_str()),
+                ReportCell::new(receipts_stat.count.blue().bold(), receipts_stat.count),
+            ];
+            for (_, aggregator) in self.metrics() {
+                let stat = aggregator
+                    .statistic(func)
+                    .unwrap_or_else(|| NumericStatistic::from(&Default::default()));
+                cells.extend(self.columns.iter().map(|column| {
+                    let value = stat.value(*column);
+                    ReportCell::new(value.bright_magenta().bold(), value)
+                }));
+            }
+            table.add_row(cells);
+        }
+        vec![table]
+    }
+}
@@ -0,0 +1,269 @@
+use owo_colors::OwoColorize;
+use prettytable::{Cell, Row, Table};
+use serde_json::{json, Map, Value};
+
+/// Defines the format of the statistic report
+/// * Table - colourized table for the terminal
+/// * Json - array of the report sections with the raw values, useful for the CI artifacts
+/// * Csv - comma separated values of every report section
+/// * Markdown - markdown tables, useful for the PR comments
+/// * Html - self-contained HTML page, useful for sharing the results outside of the terminal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReportFormat {
+    #[default]
+    Table,
+    Json,
+    Csv,
+    Markdown,
+    Html,
+}
+
+/// Single value of the report
+/// * `text` - human readable representation, which could contain the colour codes
+/// * `value` - raw value for the machine readable formats
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportCell {
+    pub text: String,
+    pub value: Value,
+}
+
+impl ReportCell {
+    pub fn new(text: impl ToString, value: impl Into<Value>) -> Self {
+        Self {
+            text: text.to_string(),
+            value: value.into(),
+        }
+    }
+}
+
+/// Section of the statistic report, e.g. "Gas usage" table
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportTable {
+    pub title: String,
+    pub header: Vec<String>,
+    pub rows: Vec<Vec<ReportCell>>,
+}
+
+impl ReportTable {
+    pub fn new(title: &str, header: Vec<String>) -> Self {
+        Self {
+            title: title.to_owned(),
+            header,
+            rows: vec![],
+        }
+    }
+
+    pub fn add_row(&mut self, row: Vec<ReportCell>) {
+        self.rows.push(row);
+    }
+
+    /// Section with the single cell of the text report, the colour codes are stripped for the raw value
+    pub fn text(title: &str, text: &str) -> Self {
+        let mut table = Self::new(title, vec!["Report".to_owned()]);
+        table.add_row(vec![ReportCell::new(text, strip_ansi(text))]);
+        table
+    }
+
+    fn to_table(&self, colored: bool) -> String {
+        let mut table = Table::new();
+        table.add_row(Row::new(
+            self.header.iter().map(|title| Cell::new(title)).collect(),
+        ));
+        for row in self.rows.iter() {
+            table.add_row(Row::new(
+                row.iter()
+                    .map(|cell| match colored {
+                        true => Cell::new(&cell.text),
+                        false => Cell::new(&strip_ansi(&cell.text)),
+                    })
+                    .collect(),
+            ));
+        }
+
+        match colored {
+            true => format!("{}\n{}", self.title.bright_yellow().bold(), table),
+            false => format!("{}\n{}", self.title, table),
+        }
+    }
+
+    pub(crate) fn to_json(&self) -> Value {
+        let rows: Vec<Value> = self
+            .rows
+            .iter()
+            .map(|row| {
+                Value::Object(
+                    self.header
+                        .iter()
+                        .cloned()
+                        .zip(row.iter().map(|cell| cell.value.clone()))
+                        .collect::<Map<_, _>>(),
+                )
+            })
+            .collect();
+
+        json!({ "title": self.title, "rows": rows })
+    }
+
+    fn to_csv(&self) -> String {
+        let mut lines = vec![format!("# {}", self.title), csv_line(self.header.iter())];
+        lines.extend(
+            self.rows
+                .iter()
+                .map(|row| csv_line(row.iter().map(|cell| strip_ansi(&cell.text)))),
+        );
+        lines.join("\n") + "\n"
+    }
+
+    fn to_markdown(&self) -> String {
+        let md_line = |cells: Vec<String>| {
+            format!(
+                "| {} |",
+                cells
+                    .iter()
+                    .map(|cell| cell.replace('|', "\\|"))
+                    .collect::<Vec<_>>()
+                    .join(" | ")
+            )
+        };
+
+        let mut lines = vec![
+            format!("### {}", self.title),
+            String::new(),
+            md_line(self.header.clone()),
+            md_line(self.header.iter().map(|_| "---".to_owned()).collect()),
+        ];
+        lines.extend(
+            self.rows
+                .iter()
+                .map(|row| md_line(row.iter().map(|cell| strip_ansi(&cell.text)).collect())),
+        );
+        lines.join("\n") + "\n"
+    }
+
+    fn to_html(&self) -> String {
+        let html_row = |tag: &str, cells: Vec<String>| {
+            let cells: String = cells
+                .iter()
+                .map(|cell| format!("<{tag}>{}</{tag}>", escape_html(cell)))
+                .collect();
+            format!("<tr>{}</tr>", cells)
+        };
+
+        let mut lines = vec![
+            format!("<h3>{}</h3>", escape_html(&self.title)),
+            "<table>".to_owned(),
+            html_row("th", self.header.clone()),
+        ];
+        lines.extend(self.rows.iter().map(|row| {
+            html_row(
+                "td",
+                row.iter().map(|cell| strip_ansi(&cell.text)).collect(),
+            )
+        }));
+        lines.push("</table>".to_owned());
+        lines.join("\n") + "\n"
+    }
+}
+
+/// Render the tables as the HTML fragment without the page wrapper
+pub(crate) fn html_tables(tables: &[ReportTable]) -> String {
+    tables
+        .iter()
+        .map(ReportTable::to_html)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Wrap the HTML fragment into the self-contained page with the inline styles
+pub(crate) fn html_page(title: &str, body: &str) -> String {
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>{title}</title>
+<style>
+body {{ font-family: sans-serif; margin: 2em; color: #222; }}
+table {{ border-collapse: collapse; margin-bottom: 2em; }}
+th, td {{ border: 1px solid #ccc; padding: 4px 8px; text-align: left; }}
+th {{ background: #f0f0f0; }}
+td {{ font-family: monospace; white-space: pre; }}
+</style>
+</head>
+<body>
+<h1>{title}</h1>
+{body}</body>
+</html>
+"#,
+        title = escape_html(title),
+        body = body
+    )
+}
+
+/// Render the report sections in the particular format
+/// * `colored` - whether the colour codes should be kept, it affects only the table format
+pub fn render_report(tables: &[ReportTable], format: ReportFormat, colored: bool) -> String {
+    match format {
+        ReportFormat::Table => tables
+            .iter()
+            .map(|table| table.to_table(colored))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        ReportFormat::Json => {
+            let sections: Vec<Value> = tables.iter().map(ReportTable::to_json).collect();
+            serde_json::to_string_pretty(&sections).unwrap_or_default() + "\n"
+        }
+        ReportFormat::Csv => tables
+            .iter()
+            .map(ReportTable::to_csv)
+            .collect::<Vec<_>>()
+            .join("\n"),
+        ReportFormat::Markdown => tables
+            .iter()
+            .map(ReportTable::to_markdown)
+            .collect::<Vec<_>>()
+            .join("\n"),
+        ReportFormat::Html => html_page("Statistic report", &html_tables(tables)),
+    }
+}
+
+/// Remove the terminal colour codes from the text
+pub fn strip_ansi(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            // skip the escape sequence till the final letter
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+pub(crate) fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// Join the values into the CSV line, quoting them if required
+fn csv_line(values: impl Iterator<Item = impl AsRef<str>>) -> String {
+    values
+        .map(|value| {
+            let value = value.as_ref();
+            if value.contains([',', '"', '\n']) {
+                format!("\"{}\"", value.replace('"', "\"\""))
+            } else {
+                value.to_owned()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
@@ -0,0 +1,81 @@
+use super::{
+    mode_printer::ModePrinter,
+    report::{ReportFormat, ReportTable},
+    statistic_consumer::{Statistic, StatisticConsumer},
+    statistic_printer::StatisticPrinter,
+};
+use crate::error::TestError;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Cloneable wrapper of the statistic consumer, which could be shared among the concurrent futures.
+/// All clones populate the same consumer, so the statistic could be printed by any of them afterwards
+/// * Note: printer mode of the consumer is captured when the wrapper is created
+#[derive(Debug, Clone)]
+pub struct SharedConsumer {
+    consumer: Arc<Mutex<Box<dyn StatisticConsumer>>>,
+    mode_printer: ModePrinter,
+}
+
+impl SharedConsumer {
+    pub fn new(consumer: impl StatisticConsumer + 'static) -> Self {
+        Self::from(Box::new(consumer) as Box<dyn StatisticConsumer>)
+    }
+
+    /// Populate the shared consumer without the mutable reference
+    pub fn consume(&self, stat: &Statistic) {
+        self.lock().consume_statistic(stat);
+    }
+
+    /// Access to the wrapped consumer, e.g. to check the aggregated values
+    pub fn lock(&self) -> MutexGuard<'_, Box<dyn StatisticConsumer>> {
+        // the statistic is still valid even if some other future panicked during the consumption
+        self.consumer
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl From<Box<dyn StatisticConsumer>> for SharedConsumer {
+    fn from(consumer: Box<dyn StatisticConsumer>) -> Self {
+        Self {
+            mode_printer: consumer.get_printer_mode().clone(),
+            consumer: Arc::new(Mutex::new(consumer)),
+        }
+    }
+}
+
+impl StatisticConsumer for SharedConsumer {
+    fn consume_statistic(&mut self, stat: &Statistic) {
+        self.consume(stat);
+    }
+
+    fn clean_statistic(&mut self) {
+        self.lock().clean_statistic();
+    }
+}
+
+impl StatisticPrinter for SharedConsumer {
+    fn get_printer_mode(&self) -> &ModePrinter {
+        &self.mode_printer
+    }
+
+    fn get_report_format(&self) -> ReportFormat {
+        self.lock().get_report_format()
+    }
+
+    fn report_tables(&self) -> Vec<ReportTable> {
+        self.lock().report_tables()
+    }
+
+    fn report_name(&self) -> String {
+        self.lock().report_name()
+    }
+
+    fn make_report(&self) -> String {
+        self.lock().make_report()
+    }
+
+    fn print_statistic(&self) -> Result<(), TestError> {
+        self.lock().print_statistic()
+    }
+}
@@ -0,0 +1,220 @@
+use super::statistic_printer::StatisticPrinter;
+use crate::error::{Result, TestError};
+use serde_json::{json, Map, Value};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Environment variable which enables the update mode of the snapshots, e.g. `UPDATE_SNAPSHOTS=1 cargo test`
+pub const UPDATE_SNAPSHOTS_ENV: &str = "UPDATE_SNAPSHOTS";
+
+/// Snapshot of the consumer report, which is compared with the checked-in snapshot file to notice the changes,
+/// e.g. in the gas or storage usage of the functions.
+/// The snapshot is the JSON form of the report tables, every row is keyed by its text values (function, account, etc.),
+/// so the snapshot doesn't depend on the order of the rows. Gas numbers drift across the nearcore versions,
+/// so the numbers could be rounded to the significant digits and compared with the tolerance.
+///
+/// The snapshot file is written instead of the comparison in the update mode, which is enabled
+/// by the `UPDATE_SNAPSHOTS` environment variable, the missing snapshot file is an error otherwise
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    path: PathBuf,
+    update_mode: bool,
+    precision: Option<u32>,
+    tolerance_percent: f64,
+    ignored_columns: Vec<String>,
+}
+
+impl Snapshot {
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            update_mode: is_update_mode(),
+            precision: None,
+            tolerance_percent: 0.,
+            ignored_columns: vec![],
+        }
+    }
+
+    /// Override the update mode of the environment, e.g. to write the snapshot from the code
+    pub fn with_update_mode(mut self, update_mode: bool) -> Self {
+        self.update_mode = update_mode;
+        self
+    }
+
+    /// Round the numbers to the significant digits, e.g. 2 digits for `2_345_678` gives `2_300_000`
+    pub fn with_precision(mut self, significant_digits: u32) -> Self {
+        self.precision = Some(significant_digits.max(1));
+        self
+    }
+
+    /// Allowed difference of the numbers in percents of the snapshot value
+    pub fn with_tolerance(mut self, tolerance_percent: f64) -> Self {
+        self.tolerance_percent = tolerance_percent;
+        self
+    }
+
+    /// Columns which are excluded from the snapshot, e.g. the ones which are not deterministic
+    pub fn with_ignored_columns(mut self, columns: &[&str]) -> Self {
+        self.ignored_columns = columns.iter().map(|column| column.to_string()).collect();
+        self
+    }
+
+    /// Deterministic JSON form of the report: `{"table": {"row key": {"column": value}}}`
+    pub fn snapshot_of(&self, printer: &(impl StatisticPrinter + ?Sized)) -> Value {
+        let mut tables = Map::new();
+        for table in printer.report_tables() {
+            let mut rows = Map::new();
+            for row in table.rows.iter() {
+                let cells: Vec<_> = table.header.iter().zip(row.iter()).collect();
+                let key = cells
+                    .iter()
+                    .filter_map(|(_, cell)| cell.value.as_str())
+                    .collect::<Vec<_>>()
+                    .join(" / ");
+                let values: Map<String, Value> = cells
+                    .into_iter()
+                    .filter(|(column, cell)| {
+                        !cell.value.is_string() && !self.ignored_columns.contains(column)
+                    })
+                    .map(|(column, cell)| (column.clone(), self.round(&cell.value)))
+                    .collect();
+
+                // rows with the same key are numbered
+                let mut row_key = key.clone();
+                let mut index = 1;
+                while rows.contains_key(&row_key) {
+                    index += 1;
+                    row_key = format!("{} #{}", key, index);
+                }
+                rows.insert(row_key, Value::Object(values));
+            }
+            tables.insert(table.title, Value::Object(rows));
+        }
+        Value::Object(tables)
+    }
+
+    /// Compare the report with the snapshot file, returns the error with all differences.
+    /// The snapshot file is written instead in the update mode
+    pub fn assert_matches(&self, printer: &(impl StatisticPrinter + ?Sized)) -> Result<()> {
+        let actual = self.snapshot_of(printer);
+        if self.update_mode {
+            return self.save(&actual);
+        }
+        if !self.path.exists() {
+            return Err(TestError::SnapshotMismatch(format!(
+                "{} doesn't exist (set {} to write it)",
+                self.path.display(),
+                UPDATE_SNAPSHOTS_ENV
+            )));
+        }
+
+        let content =
+            fs::read_to_string(&self.path).map_err(|err| TestError::Custom(err.to_string()))?;
+        let expected: Value =
+            serde_json::from_str(&content).map_err(|err| TestError::Custom(err.to_string()))?;
+
+        let mut differences = vec![];
+        self.diff("", &expected, &actual, &mut differences);
+        if differences.is_empty() {
+            return Ok(());
+        }
+
+        Err(TestError::SnapshotMismatch(format!(
+            "{} differs from the report (set {} to update it):\n{}",
+            self.path.display(),
+            UPDATE_SNAPSHOTS_ENV,
+            differences.join("\n")
+        )))
+    }
+
+    fn save(&self, snapshot: &Value) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).map_err(|err| TestError::Custom(err.to_string()))?;
+        }
+        let content = serde_json::to_string_pretty(snapshot)
+            .map_err(|err| TestError::Custom(err.to_string()))?;
+        fs::write(&self.path, content + "\n").map_err(|err| TestError::Custom(err.to_string()))
+    }
+
+    fn round(&self, value: &Value) -> Value {
+        match (value, self.precision) {
+            (Value::Number(number), Some(digits)) => {
+                let Some(float) = number.as_f64().filter(|float| *float != 0.) else {
+                    return value.clone();
+                };
+                let magnitude = float.abs().log10().floor() as i32;
+                let factor = 10f64.powi(digits as i32 - 1 - magnitude);
+                let rounded = (float * factor).round() / factor;
+                match number.is_f64() {
+                    true => json!(rounded),
+                    false => json!(rounded as i64),
+                }
+            }
+            (Value::Object(object), _) => Value::Object(
+                object
+                    .iter()
+                    .map(|(key, value)| (key.clone(), self.round(value)))
+                    .collect(),
+            ),
+            (Value::Array(array), _) => {
+                Value::Array(array.iter().map(|value| self.round(value)).collect())
+            }
+            _ => value.clone(),
+        }
+    }
+
+    // Collect the differences of the values, the numbers are compared with the tolerance
+    fn diff(&self, path: &str, expected: &Value, actual: &Value, differences: &mut Vec<String>) {
+        match (expected, actual) {
+            (Value::Object(expected), Value::Object(actual)) => {
+                for (key, expected_value) in expected.iter() {
+                    let key_path = format!("{}/{}", path, key);
+                    match actual.get(key) {
+                        Some(actual_value) => {
+                            self.diff(&key_path, expected_value, actual_value, differences)
+                        }
+                        None => differences.push(format!("{}: removed", key_path)),
+                    }
+                }
+                differences.extend(
+                    actual
+                        .keys()
+                        .filter(|key| !expected.contains_key(*key))
+                        .map(|key| format!("{}/{}: added", path, key)),
+                );
+            }
+            (Value::Number(expected_number), Value::Number(actual_number)) => {
+                let (expected_float, actual_float) = (
+                    expected_number.as_f64().unwrap_or_default(),
+                    actual_number.as_f64().unwrap_or_default(),
+                );
+                let delta_percent = match expected_float == 0. {
+                    true if actual_float == 0. => 0.,
+                    true => f64::INFINITY,
+                    false => ((actual_float - expected_float) / expected_float * 100.).abs(),
+                };
+                if delta_percent > self.tolerance_percent {
+                    differences.push(format!(
+                        "{}: {} -> {} ({:+.2}%)",
+                        path,
+                        expected,
+                        actual,
+                        delta_percent.copysign(actual_float - expected_float)
+                    ));
+                }
+            }
+            (expected, actual) if expected != actual => {
+                differences.push(format!("{}: {} -> {}", path, expected, actual))
+            }
+            _ => {}
+        }
+    }
+}
+
+fn is_update_mode() -> bool {
+    std::env::var(UPDATE_SNAPSHOTS_ENV)
+        .map(|value| !value.is_empty() && value != "0")
+        .unwrap_or_default()
+}
@@ -1,20 +1,141 @@
 use super::statistic_printer::StatisticPrinter;
-use crate::tx_result::{TxResult, TxResultDetails};
+use crate::{
+    error::TestError,
+    tx_result::{
+        duration_between,
+        serialization::{option_as_string, option_timestamp},
+        TxResult, TxResultDetails,
+    },
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    time::{Duration, SystemTime},
+};
+use workspaces::{
+    types::{Balance, CryptoHash},
+    AccountId,
+};
 
 /// This struct aggregates all required statistic data related to the smart-contract method call
-#[derive(Debug, Clone, Default)]
+/// * Note: it is serializable with any serde format, so the statistic of the run could be saved into the trace
+///   and consumed later. The balances are stored as strings, the hashes in the base58 representation
+///   and the timestamps as nanoseconds since the UNIX epoch
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Statistic {
     pub func_name: String,
     pub storage_usage: Option<i64>,
+    // storage usage changes of every account which executed the receipts
+    #[serde(default)]
+    pub storage_deltas: HashMap<AccountId, i64>,
     pub details: TxResultDetails,
+    // height of the block which included the transaction, populated by `measure_block_height`
+    pub block_height: Option<u64>,
+    // height of the block reached after the call, populated by the batch which tracks blocks
+    pub reached_block_height: Option<u64>,
+    #[serde(default, with = "option_as_string")]
+    pub block_hash: Option<CryptoHash>,
+    #[serde(default, with = "option_as_string")]
+    pub tx_hash: Option<CryptoHash>,
+    // contract, caller, serialized JSON arguments and attached deposit of the call
+    pub contract_id: Option<AccountId>,
+    pub caller: Option<AccountId>,
+    pub args: Option<String>,
+    #[serde(default, with = "option_as_string")]
+    pub deposit: Option<Balance>,
+    // number of the RPC call retries made according to the retry policy
+    #[serde(default)]
+    pub retries: u32,
+    // wall-clock time when the call was started and finished
+    #[serde(default, with = "option_timestamp")]
+    pub started_at: Option<SystemTime>,
+    #[serde(default, with = "option_timestamp")]
+    pub finished_at: Option<SystemTime>,
+    // error of the failed call, it is populated by the batch in the continue-on-error mode
+    pub error: Option<String>,
+    // labels of the operation, e.g. the step of the scenario, they are attached by the batch operations
+    #[serde(default)]
+    pub labels: Vec<String>,
+}
+
+impl Statistic {
+    /// Statistic of the failed call, the call info and the outcomes of the transaction (if it was executed)
+    /// could be attached with `with_call_info` and `with_details`, other fields are not populated.
+    /// * Note: the consumers of the gas and receipts usage skip the failed calls
+    pub fn failed(func_name: &str, error: &TestError) -> Self {
+        Self {
+            func_name: func_name.to_owned(),
+            error: Some(error.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Set the details of the call, e.g. the outcomes of the failed transaction
+    pub fn with_details(mut self, details: TxResultDetails) -> Self {
+        self.details = details;
+        self
+    }
+
+    /// Set the contract, caller, serialized JSON arguments and attached deposit of the call,
+    /// e.g. for the failed call, which doesn't have the statistic of the result
+    /// * `caller` and `deposit` - None for the view calls
+    pub fn with_call_info(
+        mut self,
+        contract_id: &AccountId,
+        caller: Option<AccountId>,
+        args: String,
+        deposit: Option<Balance>,
+    ) -> Self {
+        self.contract_id = Some(contract_id.clone());
+        self.caller = caller;
+        self.args = Some(args);
+        self.deposit = deposit;
+        self
+    }
+
+    pub fn is_failed(&self) -> bool {
+        self.error.is_some()
+    }
+
+    /// Attach the label, so the statistic could be grouped by it in the reports
+    pub fn with_label(mut self, label: &str) -> Self {
+        self.labels.push(label.to_owned());
+        self
+    }
+
+    /// Wall-clock duration of the call, it is known only for the calls with the recorded timestamps
+    pub fn duration(&self) -> Option<Duration> {
+        duration_between(self.started_at, self.finished_at)
+    }
 }
 
 impl<T> From<TxResult<T>> for Statistic {
     fn from(tx_res: TxResult<T>) -> Self {
+        Self::from(&tx_res)
+    }
+}
+
+// The value of the call is not required for the statistic, so it doesn't have to be cloneable
+impl<T> From<&TxResult<T>> for Statistic {
+    fn from(tx_res: &TxResult<T>) -> Self {
         Statistic {
-            func_name: tx_res.func_name,
+            func_name: tx_res.func_name.clone(),
             storage_usage: tx_res.storage_usage,
-            details: tx_res.details,
+            storage_deltas: tx_res.storage_deltas.clone(),
+            details: tx_res.details.clone(),
+            block_height: tx_res.block_height,
+            reached_block_height: None,
+            block_hash: tx_res.block_hash,
+            tx_hash: tx_res.tx_hash,
+            contract_id: tx_res.contract_id.clone(),
+            caller: tx_res.caller.clone(),
+            args: tx_res.args.clone(),
+            deposit: tx_res.deposit,
+            retries: tx_res.retries,
+            started_at: tx_res.started_at,
+            finished_at: tx_res.finished_at,
+            error: None,
+            labels: vec![],
         }
     }
 }
@@ -28,3 +149,14 @@ pub trait StatisticConsumer: Sync + Send + std::fmt::Debug + StatisticPrinter {
     fn consume_statistic(&mut self, stat: &Statistic);
     fn clean_statistic(&mut self);
 }
+
+// Boxed consumers could be used along with the plain ones, e.g. in the statistic groups
+impl<C: StatisticConsumer + ?Sized> StatisticConsumer for Box<C> {
+    fn consume_statistic(&mut self, stat: &Statistic) {
+        self.as_mut().consume_statistic(stat)
+    }
+
+    fn clean_statistic(&mut self) {
+        self.as_mut().clean_statistic()
+    }
+}
@@ -4,16 +4,16 @@ use super::statistic_consumer::{Statistic, StatisticConsumer};
 /// * Note: it should be implemented only for the statistic groups for single statistic use
 pub trait StatisticGroupExt {
     // Populate statistic for provided consumers and create a general report into the result String
-    fn process_statistic(self, consumers: &mut [&mut Box<dyn StatisticConsumer>]) -> String;
+    fn process_statistic<C: StatisticConsumer + ?Sized>(self, consumers: &mut [&mut C]) -> String;
 
-    fn populate_statistic<'a, 'b>(
+    fn populate_statistic<'a, 'b, C: StatisticConsumer + ?Sized>(
         &self,
-        consumers: &'a mut [&'b mut Box<dyn StatisticConsumer>],
-    ) -> &'a mut [&'b mut Box<dyn StatisticConsumer>];
+        consumers: &'a mut [&'b mut C],
+    ) -> &'a mut [&'b mut C];
 }
 
 impl StatisticGroupExt for Vec<Statistic> {
-    fn process_statistic(self, consumers: &mut [&mut Box<dyn StatisticConsumer>]) -> String {
+    fn process_statistic<C: StatisticConsumer + ?Sized>(self, consumers: &mut [&mut C]) -> String {
         let mut result = String::new();
 
         consumers.iter_mut().for_each(|consumer| {
@@ -26,10 +26,10 @@ impl StatisticGroupExt for Vec<Statistic> {
         result
     }
 
-    fn populate_statistic<'a, 'b>(
+    fn populate_statistic<'a, 'b, C: StatisticConsumer + ?Sized>(
         &self,
-        consumers: &'a mut [&'b mut Box<dyn StatisticConsumer>],
-    ) -> &'a mut [&'b mut Box<dyn StatisticConsumer>] {
+        consumers: &'a mut [&'b mut C],
+    ) -> &'a mut [&'b mut C] {
         consumers.iter_mut().for_each(|consumer| {
             self.iter()
                 .for_each(|stat| consumer.consume_statistic(stat));
@@ -40,7 +40,7 @@ impl StatisticGroupExt for Vec<Statistic> {
 }
 
 impl StatisticGroupExt for Statistic {
-    fn process_statistic(self, consumers: &mut [&mut Box<dyn StatisticConsumer>]) -> String {
+    fn process_statistic<C: StatisticConsumer + ?Sized>(self, consumers: &mut [&mut C]) -> String {
         let mut result = String::new();
 
         for consumer in consumers.iter_mut() {
@@ -51,10 +51,10 @@ impl StatisticGroupExt for Statistic {
         result
     }
 
-    fn populate_statistic<'a, 'b>(
+    fn populate_statistic<'a, 'b, C: StatisticConsumer + ?Sized>(
         &self,
-        consumers: &'a mut [&'b mut Box<dyn StatisticConsumer>],
-    ) -> &'a mut [&'b mut Box<dyn StatisticConsumer>] {
+        consumers: &'a mut [&'b mut C],
+    ) -> &'a mut [&'b mut C] {
         consumers.iter_mut().for_each(|consumer| {
             consumer.consume_statistic(&self);
         });
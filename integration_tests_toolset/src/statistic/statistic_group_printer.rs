@@ -1,12 +1,15 @@
-use super::statistic_consumer::StatisticConsumer;
+use super::{combined_report::CombinedReport, statistic_consumer::StatisticConsumer};
 use crate::error::TestError;
 
 /// Interface for printing aggregated statistic from multiple consumers(stored in some group like: array, vector, etc.)
 pub trait StatisticGroupPrinter {
     fn print_statistic(&self) -> Result<(), TestError>;
+
+    /// Combine the reports of all consumers into one report with the section per each consumer
+    fn combined_report(&self, run_name: &str) -> CombinedReport;
 }
 
-impl StatisticGroupPrinter for &mut [&mut Box<dyn StatisticConsumer>] {
+impl<C: StatisticConsumer + ?Sized> StatisticGroupPrinter for &mut [&mut C] {
     fn print_statistic(&self) -> Result<(), TestError> {
         for consumer in self.iter() {
             consumer.print_statistic()?;
@@ -14,6 +17,14 @@ impl StatisticGroupPrinter for &mut [&mut Box<dyn StatisticConsumer>] {
 
         Ok(())
     }
+
+    fn combined_report(&self, run_name: &str) -> CombinedReport {
+        let mut report = CombinedReport::new(run_name);
+        for consumer in self.iter() {
+            report.add_consumer(&**consumer);
+        }
+        report
+    }
 }
 
 impl<const N: usize> StatisticGroupPrinter for [Box<dyn StatisticConsumer>; N] {
@@ -24,4 +35,12 @@ impl<const N: usize> StatisticGroupPrinter for [Box<dyn StatisticConsumer>; N] {
 
         Ok(())
     }
+
+    fn combined_report(&self, run_name: &str) -> CombinedReport {
+        let mut report = CombinedReport::new(run_name);
+        for consumer in self.iter() {
+            report.add_consumer(consumer.as_ref());
+        }
+        report
+    }
 }
@@ -1,16 +1,66 @@
-use super::mode_printer::ModePrinter;
+use super::{
+    mode_printer::ModePrinter,
+    report::{render_report, ReportFormat, ReportTable},
+};
 use crate::error::TestError;
 
 /// Interface for printing the statistic.
-/// Also it provides possibility to structure stored statistic data with the make_report method
+/// Also it provides possibility to structure stored statistic data with the report_tables method,
+/// which are rendered by the make_report method according to the report format
+/// * Note: the printers which build the text report themselves could return it with `ReportTable::text`
 pub trait StatisticPrinter {
     fn get_printer_mode(&self) -> &ModePrinter;
 
-    fn make_report(&self) -> String;
+    fn get_report_format(&self) -> ReportFormat {
+        ReportFormat::Table
+    }
+
+    /// Report sections, they are rendered by `make_report` and `print_statistic`
+    fn report_tables(&self) -> Vec<ReportTable>;
+
+    /// Name of the report section in the combined report, it is the type name of the printer by default
+    fn report_name(&self) -> String {
+        let type_name = std::any::type_name::<Self>();
+        let type_name = type_name.split('<').next().unwrap_or(type_name);
+        type_name
+            .rsplit("::")
+            .next()
+            .unwrap_or(type_name)
+            .to_owned()
+    }
+
+    fn make_report(&self) -> String {
+        render_report(&self.report_tables(), self.get_report_format(), true)
+    }
 
     fn print_statistic(&self) -> Result<(), TestError> {
-        let result = self.make_report();
         let printer_mode = self.get_printer_mode();
-        printer_mode.print(result.as_bytes())
+        printer_mode.print_report(&self.report_tables(), self.get_report_format())
+    }
+}
+
+impl<P: StatisticPrinter + ?Sized> StatisticPrinter for Box<P> {
+    fn get_printer_mode(&self) -> &ModePrinter {
+        self.as_ref().get_printer_mode()
+    }
+
+    fn get_report_format(&self) -> ReportFormat {
+        self.as_ref().get_report_format()
+    }
+
+    fn report_tables(&self) -> Vec<ReportTable> {
+        self.as_ref().report_tables()
+    }
+
+    fn report_name(&self) -> String {
+        self.as_ref().report_name()
+    }
+
+    fn make_report(&self) -> String {
+        self.as_ref().make_report()
+    }
+
+    fn print_statistic(&self) -> Result<(), TestError> {
+        self.as_ref().print_statistic()
     }
 }
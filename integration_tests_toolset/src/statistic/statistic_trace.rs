@@ -0,0 +1,160 @@
+use super::{
+    mode_printer::ModePrinter,
+    report::{ReportCell, ReportFormat, ReportTable},
+    statistic_consumer::{Statistic, StatisticConsumer},
+    statistic_printer::StatisticPrinter,
+};
+use crate::error::{Result, TestError};
+use owo_colors::OwoColorize;
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, LineWriter, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+};
+
+/// Statistic consumer which streams every consumed statistic into the JSONL trace file, one record per line.
+/// The trace could be replayed later through any set of consumers with `replay_trace`,
+/// so the runs could be post-processed or compared without the sandbox
+/// * Note: write errors don't interrupt the scenario, the first one is returned by `flush`
+#[derive(Debug)]
+pub struct TraceWriter {
+    path: PathBuf,
+    file: LineWriter<File>,
+    records: u64,
+    error: Option<String>,
+    mode_printer: ModePrinter,
+    report_format: ReportFormat,
+}
+
+impl TraceWriter {
+    /// Create the trace file, the previous content of the file is truncated
+    pub fn new(path: impl AsRef<Path>, mode_printer: ModePrinter) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let file = File::create(&path).map_err(|err| TestError::Custom(err.to_string()))?;
+
+        Ok(Self {
+            path,
+            file: LineWriter::new(file),
+            records: 0,
+            error: None,
+            mode_printer,
+            report_format: ReportFormat::default(),
+        })
+    }
+
+    pub fn with_report_format(mut self, report_format: ReportFormat) -> Self {
+        self.report_format = report_format;
+        self
+    }
+
+    /// Number of the records written into the trace
+    pub fn records(&self) -> u64 {
+        self.records
+    }
+
+    /// Flush the trace file, returns the first error occurred during the writing
+    pub fn flush(&mut self) -> Result<()> {
+        if let Some(error) = self.error.take() {
+            return Err(TestError::Custom(error));
+        }
+        self.file
+            .flush()
+            .map_err(|err| TestError::Custom(err.to_string()))
+    }
+
+    fn write(&mut self, stat: &Statistic) -> std::io::Result<()> {
+        let line = serde_json::to_string(stat)?;
+        self.file.write_all(line.as_bytes())?;
+        self.file.write_all(b"\n")
+    }
+}
+
+impl StatisticConsumer for TraceWriter {
+    fn consume_statistic(&mut self, stat: &Statistic) {
+        match self.write(stat) {
+            Ok(()) => self.records += 1,
+            Err(err) => {
+                self.error.get_or_insert_with(|| err.to_string());
+            }
+        }
+    }
+
+    // The trace file is truncated
+    fn clean_statistic(&mut self) {
+        let result = self.file.flush().and_then(|_| {
+            let file = self.file.get_mut();
+            file.set_len(0)?;
+            file.seek(SeekFrom::Start(0)).map(|_| ())
+        });
+        if let Err(err) = result {
+            self.error.get_or_insert_with(|| err.to_string());
+        }
+        self.records = 0;
+    }
+}
+
+impl StatisticPrinter for TraceWriter {
+    fn get_printer_mode(&self) -> &ModePrinter {
+        &self.mode_printer
+    }
+
+    fn get_report_format(&self) -> ReportFormat {
+        self.report_format
+    }
+
+    fn report_tables(&self) -> Vec<ReportTable> {
+        let path = self.path.display().to_string();
+        let mut table = ReportTable::new(
+            "Statistic trace",
+            vec!["File".to_owned(), "Records".to_owned()],
+        );
+        table.add_row(vec![
+            ReportCell::new(path.green().bold(), path.as_str()),
+            ReportCell::new(self.records.blue(), self.records),
+        ]);
+        vec![table]
+    }
+}
+
+/// Read all records of the JSONL trace file, empty lines are skipped
+pub fn read_trace(path: impl AsRef<Path>) -> Result<Vec<Statistic>> {
+    let mut stats = vec![];
+    for_each_record(path, |stat| stats.push(stat))?;
+    Ok(stats)
+}
+
+/// Populate the consumers with every record of the JSONL trace file, the file is read line by line.
+/// Returns the number of the replayed records
+pub fn replay_trace<C: StatisticConsumer + ?Sized>(
+    path: impl AsRef<Path>,
+    consumers: &mut [&mut C],
+) -> Result<usize> {
+    for_each_record(path, |stat| {
+        for consumer in consumers.iter_mut() {
+            consumer.consume_statistic(&stat);
+        }
+    })
+}
+
+fn for_each_record(path: impl AsRef<Path>, mut callback: impl FnMut(Statistic)) -> Result<usize> {
+    let file = File::open(path).map_err(|err| TestError::Custom(err.to_string()))?;
+
+    let mut records = 0;
+    for (index, line) in BufReader::new(file).lines().enumerate() {
+        let line = line.map_err(|err| TestError::Custom(err.to_string()))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let stat = serde_json::from_str(&line).map_err(|err| {
+            TestError::Custom(format!(
+                "Invalid trace record at line {}: {}",
+                index + 1,
+                err
+            ))
+        })?;
+        callback(stat);
+        records += 1;
+    }
+    Ok(records)
+}
@@ -1,75 +1,85 @@
 use super::{
+    baseline::{BaselineComparator, BaselineEntry},
+    group_by::GroupBy,
     mode_printer::ModePrinter,
+    numeric_aggregator::{
+        NumericAggregator, NumericStatistic, OperationValues, StatisticColumn, DEFAULT_COLUMNS,
+    },
+    report::{ReportCell, ReportFormat, ReportTable},
     statistic_consumer::{Statistic, StatisticConsumer},
     statistic_printer::StatisticPrinter,
 };
 use owo_colors::OwoColorize;
-use prettytable::{row, Table};
-use std::collections::{BinaryHeap, HashMap};
+use std::collections::BTreeMap;
+use workspaces::AccountId;
 
-/// Struct for representing the range of values which shows the storage usage of the particular operation.
-/// It should be used in scenarios with multiple storage measurements for the same operation.
-#[derive(Debug)]
-pub struct OperationStorageUsage {
-    pub heap: BinaryHeap<i64>,
-}
-
-/// Struct for representing storage statistical values
-#[derive(Debug)]
-pub struct OperationStorageStatistic {
-    pub min: i64,
-    pub max: i64,
-    pub median: i64,
-}
+/// Range of values which shows the storage usage of the particular operation
+pub type OperationStorageUsage = OperationValues<i64>;
 
-impl From<&OperationStorageUsage> for OperationStorageStatistic {
-    fn from(op_storage: &OperationStorageUsage) -> Self {
-        let storage_vec: Vec<i64> = op_storage.heap.clone().into_sorted_vec();
-        if storage_vec.is_empty() {
-            return Self {
-                min: 0,
-                max: 0,
-                median: 0,
-            };
-        } else {
-            return Self {
-                min: storage_vec.first().cloned().unwrap_or_default(),
-                max: storage_vec.last().cloned().unwrap_or_default(),
-                median: {
-                    let mid = storage_vec.len() / 2;
-                    if storage_vec.len() % 2 == 0 {
-                        (storage_vec[mid] + storage_vec[mid - 1]) / 2
-                    } else {
-                        storage_vec[mid]
-                    }
-                },
-            };
-        }
-    }
-}
+/// Storage statistical values of the particular operation
+pub type OperationStorageStatistic = NumericStatistic<i64>;
 
 /// Struct for representing storage usage per each function
+/// * `account_storage` - breakdown of the storage usage per each account which executed the receipts of the function call
+///
+/// The statistic could be grouped by the labels of the operations instead of the function with `with_group_by`
 #[derive(Debug)]
 pub struct StorageUsage {
-    pub func_storage: HashMap<String, OperationStorageUsage>,
+    pub func_storage: NumericAggregator<String, i64>,
+    pub account_storage: NumericAggregator<(String, AccountId), i64>,
+    group_by: GroupBy,
+    columns: Vec<StatisticColumn>,
     mode_printer: ModePrinter,
+    report_format: ReportFormat,
 }
 
 impl StorageUsage {
-    pub fn new(mode_printer: ModePrinter) -> Self {
+    /// * `columns` - statistical values which should be printed in the report
+    pub fn new(mode_printer: ModePrinter, columns: &[StatisticColumn]) -> Self {
         Self {
-            func_storage: HashMap::new(),
+            func_storage: NumericAggregator::default(),
+            account_storage: NumericAggregator::default(),
+            group_by: GroupBy::default(),
+            columns: columns.to_vec(),
             mode_printer,
+            report_format: ReportFormat::default(),
         }
     }
+
+    pub fn with_report_format(mut self, report_format: ReportFormat) -> Self {
+        self.report_format = report_format;
+        self
+    }
+
+    /// Group the statistic by the function, the label of the operation or both
+    pub fn with_group_by(mut self, group_by: GroupBy) -> Self {
+        self.group_by = group_by;
+        self
+    }
+
+    fn header(&self, keys: &[&str]) -> Vec<String> {
+        let mut header: Vec<_> = keys.iter().map(|key| key.to_string()).collect();
+        header.push("Count".to_owned());
+        header.extend(self.columns.iter().map(|column| column.title().to_owned()));
+        header
+    }
+
+    fn values(&self, storage_stat: &OperationStorageStatistic) -> Vec<ReportCell> {
+        let mut cells = vec![ReportCell::new(
+            storage_stat.count.blue().bold(),
+            storage_stat.count,
+        )];
+        cells.extend(self.columns.iter().map(|column| {
+            let storage = storage_stat.value(*column);
+            ReportCell::new(storage.print_storage(), storage)
+        }));
+        cells
+    }
 }
 
 impl Default for StorageUsage {
     fn default() -> Self {
-        Self {
-            func_storage: HashMap::new(),
-            mode_printer: Default::default(),
-        }
+        Self::new(Default::default(), &DEFAULT_COLUMNS)
     }
 }
 
@@ -95,19 +105,20 @@ impl StoragePrinter for i64 {
 
 impl StatisticConsumer for StorageUsage {
     fn consume_statistic(&mut self, stat: &Statistic) {
+        let key = self.group_by.key(stat);
         if let Some(storage_usage) = &stat.storage_usage {
-            let op_storage = self
-                .func_storage
-                .entry(stat.func_name.clone())
-                .or_insert_with(|| OperationStorageUsage {
-                    heap: BinaryHeap::new(),
-                });
-            op_storage.heap.push(*storage_usage);
+            self.func_storage.push(key.clone(), *storage_usage);
+        }
+
+        for (account_id, storage_delta) in stat.storage_deltas.iter() {
+            self.account_storage
+                .push((key.clone(), account_id.clone()), *storage_delta);
         }
     }
 
     fn clean_statistic(&mut self) {
         self.func_storage.clear();
+        self.account_storage.clear();
     }
 }
 
@@ -116,35 +127,52 @@ impl StatisticPrinter for StorageUsage {
         &self.mode_printer
     }
 
-    fn make_report(&self) -> String {
-        let mut storage_stat_vec: Vec<_> = self
-            .func_storage
-            .iter()
-            .map(|(func, storage)| {
-                let storage_stat = OperationStorageStatistic::from(storage);
-                (
-                    func,
-                    storage.heap.len(),
-                    storage_stat.min,
-                    storage_stat.median,
-                    storage_stat.max,
-                )
-            })
-            .collect();
-
-        storage_stat_vec.sort_by(|a, b| b.3.cmp(&a.3));
-
-        let mut table = Table::new();
-        table.add_row(row!["Function", "Count", "Min", "Median", "Max"]);
-        for (func, count, min, median, max) in storage_stat_vec.iter() {
-            table.add_row(row![
-                func.green().bold(),
-                count.to_string().blue().bold(),
-                min.print_storage(),
-                median.print_storage(),
-                max.print_storage()
-            ]);
+    fn get_report_format(&self) -> ReportFormat {
+        self.report_format
+    }
+
+    fn report_tables(&self) -> Vec<ReportTable> {
+        let mut table = ReportTable::new("Storage usage", self.header(&[self.group_by.title()]));
+        for (func, storage_stat) in self.func_storage.statistics() {
+            let mut cells = vec![ReportCell::new(func.green().bold(), func.as_str())];
+            cells.extend(self.values(&storage_stat));
+            table.add_row(cells);
         }
-        format!("{}\n{}", "Storage usage".bright_yellow().bold(), table)
+        let mut tables = vec![table];
+
+        if !self.account_storage.is_empty() {
+            let mut account_stat_vec = self.account_storage.statistics();
+            account_stat_vec.sort_by(|a, b| a.0 .0.cmp(&b.0 .0));
+
+            let mut table = ReportTable::new(
+                "Storage usage by account",
+                self.header(&[self.group_by.title(), "Account"]),
+            );
+            for ((func, account_id), storage_stat) in account_stat_vec.iter() {
+                let mut cells = vec![
+                    ReportCell::new(func.green().bold(), func.as_str()),
+                    ReportCell::new(account_id.as_str().cyan().bold(), account_id.as_str()),
+                ];
+                cells.extend(self.values(storage_stat));
+                table.add_row(cells);
+            }
+            tables.push(table);
+        }
+
+        tables
+    }
+}
+
+impl BaselineComparator for StorageUsage {
+    fn baseline_section(&self) -> &'static str {
+        "storage"
+    }
+
+    fn baseline_entries(&self) -> BTreeMap<String, BaselineEntry> {
+        self.func_storage
+            .statistics()
+            .into_iter()
+            .map(|(func, stat)| (func.clone(), BaselineEntry::from(&stat)))
+            .collect()
     }
 }
@@ -0,0 +1,182 @@
+use super::{
+    error_rate::panic_message,
+    mode_printer::ModePrinter,
+    report::{ReportCell, ReportFormat, ReportTable},
+    statistic_consumer::{Statistic, StatisticConsumer},
+    statistic_printer::StatisticPrinter,
+};
+use crate::tx_result::TxResultDetails;
+use owo_colors::OwoColorize;
+use serde_json::Value;
+use std::time::{Duration, SystemTime};
+
+// Width of the timeline bar in characters
+const BAR_WIDTH: usize = 40;
+
+/// Single call on the timeline
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimelineEntry {
+    pub func_name: String,
+    pub started_at: SystemTime,
+    pub duration: Duration,
+    pub failed: bool,
+}
+
+/// Failed call with the receipt tree of the transaction, the tree is known only for the executed calls
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FailureEntry {
+    pub func_name: String,
+    pub error: String,
+    pub receipt_tree: Option<String>,
+}
+
+/// Structure to store the timeline of the calls, e.g. the operations of the batch, and the failed calls.
+/// The timeline shows the start and the duration of every call relative to the first one,
+/// so the concurrency of the batch operations is visible in the report
+/// * Note: calls without the recorded timestamps are not placed on the timeline
+#[derive(Debug, Default)]
+pub struct Timeline {
+    pub entries: Vec<TimelineEntry>,
+    pub failures: Vec<FailureEntry>,
+    mode_printer: ModePrinter,
+    report_format: ReportFormat,
+}
+
+impl Timeline {
+    pub fn new(mode_printer: ModePrinter) -> Self {
+        Self {
+            mode_printer,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_report_format(mut self, report_format: ReportFormat) -> Self {
+        self.report_format = report_format;
+        self
+    }
+
+    // Text bar of the call within the whole timeline span
+    fn bar(&self, entry: &TimelineEntry, first: SystemTime, span: Duration) -> String {
+        let position = |duration: Duration| match span.is_zero() {
+            true => 0,
+            false => (duration.as_secs_f64() / span.as_secs_f64() * BAR_WIDTH as f64) as usize,
+        };
+        let offset = position(entry.started_at.duration_since(first).unwrap_or_default());
+        let length = position(entry.duration).clamp(1, BAR_WIDTH.saturating_sub(offset).max(1));
+        format!("{}{}", " ".repeat(offset), "█".repeat(length))
+    }
+}
+
+impl StatisticConsumer for Timeline {
+    fn consume_statistic(&mut self, stat: &Statistic) {
+        if let (Some(started_at), Some(duration)) = (stat.started_at, stat.duration()) {
+            self.entries.push(TimelineEntry {
+                func_name: stat.func_name.clone(),
+                started_at,
+                duration,
+                failed: stat.is_failed(),
+            });
+        }
+
+        if let Some(error) = &stat.error {
+            self.failures.push(FailureEntry {
+                func_name: stat.func_name.clone(),
+                error: panic_message(error),
+                receipt_tree: match &stat.details {
+                    TxResultDetails::Call(call_result) => Some(call_result.receipt_tree()),
+                    TxResultDetails::View(_) => None,
+                },
+            });
+        }
+    }
+
+    fn clean_statistic(&mut self) {
+        self.entries.clear();
+        self.failures.clear();
+    }
+}
+
+impl StatisticPrinter for Timeline {
+    fn get_printer_mode(&self) -> &ModePrinter {
+        &self.mode_printer
+    }
+
+    fn get_report_format(&self) -> ReportFormat {
+        self.report_format
+    }
+
+    fn report_tables(&self) -> Vec<ReportTable> {
+        let mut entries: Vec<_> = self.entries.iter().collect();
+        entries.sort_by_key(|entry| entry.started_at);
+
+        let first = entries
+            .first()
+            .map(|entry| entry.started_at)
+            .unwrap_or(SystemTime::UNIX_EPOCH);
+        let span = entries
+            .iter()
+            .map(|entry| (entry.started_at + entry.duration).duration_since(first))
+            .filter_map(|duration| duration.ok())
+            .max()
+            .unwrap_or_default();
+
+        let mut timeline_table = ReportTable::new(
+            "Timeline",
+            ["#", "Function", "Start", "Duration", "Status", "Timeline"]
+                .iter()
+                .map(|title| title.to_string())
+                .collect(),
+        );
+        let millis = |duration: Duration| (duration.as_secs_f64() * 1_000_000.).round() / 1_000.;
+        for (index, entry) in entries.into_iter().enumerate() {
+            let start = millis(entry.started_at.duration_since(first).unwrap_or_default());
+            let duration = millis(entry.duration);
+            let bar = self.bar(entry, first, span);
+            let (status, status_text, bar_text) = match entry.failed {
+                true => (
+                    "failed",
+                    "failed".red().bold().to_string(),
+                    bar.red().to_string(),
+                ),
+                false => (
+                    "ok",
+                    "ok".green().bold().to_string(),
+                    bar.green().to_string(),
+                ),
+            };
+
+            timeline_table.add_row(vec![
+                ReportCell::new(index + 1, index + 1),
+                ReportCell::new(entry.func_name.green().bold(), entry.func_name.as_str()),
+                ReportCell::new(format!("{:.3} ms", start), start),
+                ReportCell::new(format!("{:.3} ms", duration).bright_magenta(), duration),
+                ReportCell::new(status_text, status),
+                ReportCell::new(bar_text, bar),
+            ]);
+        }
+        let mut tables = vec![timeline_table];
+
+        if !self.failures.is_empty() {
+            let mut failures_table = ReportTable::new(
+                "Failures",
+                ["Function", "Error", "Receipts"]
+                    .iter()
+                    .map(|title| title.to_string())
+                    .collect(),
+            );
+            for failure in self.failures.iter() {
+                failures_table.add_row(vec![
+                    ReportCell::new(failure.func_name.green().bold(), failure.func_name.as_str()),
+                    ReportCell::new(failure.error.red(), failure.error.as_str()),
+                    match &failure.receipt_tree {
+                        Some(receipt_tree) => ReportCell::new(receipt_tree, receipt_tree.as_str()),
+                        None => ReportCell::new("-", Value::Null),
+                    },
+                ]);
+            }
+            tables.push(failures_table);
+        }
+
+        tables
+    }
+}
@@ -0,0 +1,185 @@
+use super::{CallResult, Result};
+use crate::error::TestError;
+use near_jsonrpc_client::{
+    methods::{
+        block::RpcBlockRequest,
+        query::{RpcQueryError, RpcQueryRequest},
+        EXPERIMENTAL_changes::RpcStateChangesInBlockByTypeRequest,
+    },
+    JsonRpcClient,
+};
+use near_jsonrpc_primitives::types::query::QueryResponseKind;
+use near_primitives::{
+    hash::CryptoHash,
+    types::{BlockId, BlockReference},
+    views::{
+        AccountView, QueryRequest, StateChangeCauseView, StateChangeValueView,
+        StateChangesRequestView,
+    },
+};
+use std::collections::{HashMap, HashSet};
+use workspaces::{network::Network, AccountId, Worker};
+
+/// Changes of the accounts which executed the transaction or its receipts.
+/// The changes are collected from the state changes of the blocks where the outcomes were executed,
+/// and only the changes caused by the transaction or its receipts are kept, so the calls which touch
+/// the same accounts in the same blocks (e.g. the concurrent operations of the batch) don't affect the result.
+/// * Note: accounts which are created or deleted by the call are skipped
+#[derive(Debug, Clone, Default)]
+pub struct AccountChanges {
+    // account states before and after every change made by the call, in the order of the changes
+    pub changes: HashMap<AccountId, Vec<(AccountView, AccountView)>>,
+}
+
+impl AccountChanges {
+    pub async fn take(
+        worker: &Worker<impl Network + 'static>,
+        call_result: &CallResult,
+    ) -> Result<Self> {
+        let client = JsonRpcClient::connect(worker.rpc_addr());
+        let causes: HashSet<CryptoHash> = call_result
+            .outcomes()
+            .map(|outcome| CryptoHash(outcome.id().0))
+            .collect();
+        let mut block_hashes = vec![];
+        for outcome in call_result.outcomes() {
+            let block_hash = CryptoHash(outcome.block_hash.0);
+            if !block_hashes.contains(&block_hash) {
+                block_hashes.push(block_hash);
+            }
+        }
+
+        // accounts which didn't change are reported with the zero delta
+        let account_ids: Vec<AccountId> = call_result.executor_ids().into_iter().collect();
+        let mut changes: HashMap<AccountId, Vec<_>> = account_ids
+            .iter()
+            .map(|account_id| (account_id.clone(), vec![]))
+            .collect();
+        let mut skipped = HashSet::new();
+        for block_hash in block_hashes {
+            let block_changes = client
+                .call(RpcStateChangesInBlockByTypeRequest {
+                    block_reference: BlockReference::BlockId(BlockId::Hash(block_hash)),
+                    state_changes_request: StateChangesRequestView::AccountChanges {
+                        account_ids: account_ids.clone(),
+                    },
+                })
+                .await?;
+
+            // states of the accounts during the block, the changes of every account are listed in the order
+            // of their application, so the previous change is the state before the next one
+            let mut states: HashMap<AccountId, Option<AccountView>> = HashMap::new();
+            let mut prev_block_hash = None;
+            for change in block_changes.changes {
+                let (account_id, after) = match change.value {
+                    StateChangeValueView::AccountUpdate {
+                        account_id,
+                        account,
+                    } => (account_id, Some(account)),
+                    StateChangeValueView::AccountDeletion { account_id } => (account_id, None),
+                    _ => continue,
+                };
+                let before = match states.remove(&account_id) {
+                    Some(state) => state,
+                    None => {
+                        let prev_block_hash = match prev_block_hash {
+                            Some(prev_block_hash) => prev_block_hash,
+                            None => {
+                                let block = client
+                                    .call(RpcBlockRequest {
+                                        block_reference: BlockReference::BlockId(BlockId::Hash(
+                                            block_hash,
+                                        )),
+                                    })
+                                    .await?;
+                                *prev_block_hash.insert(block.header.prev_hash)
+                            }
+                        };
+                        view_account(&client, prev_block_hash, &account_id).await?
+                    }
+                };
+
+                if is_caused_by(&change.cause, &causes) {
+                    match (&before, &after) {
+                        (Some(before), Some(after)) => changes
+                            .entry(account_id.clone())
+                            .or_default()
+                            .push((before.clone(), after.clone())),
+                        _ => {
+                            skipped.insert(account_id.clone());
+                        }
+                    }
+                }
+                states.insert(account_id, after);
+            }
+        }
+
+        changes.retain(|account_id, _| !skipped.contains(account_id));
+        Ok(Self { changes })
+    }
+
+    /// Changes of the value extracted from the account state for every account
+    pub fn deltas<V>(&self, value: impl Fn(&AccountView) -> V) -> HashMap<AccountId, V>
+    where
+        V: std::ops::Sub<Output = V> + std::iter::Sum,
+    {
+        self.changes
+            .iter()
+            .map(|(account_id, changes)| {
+                (
+                    account_id.clone(),
+                    changes
+                        .iter()
+                        .map(|(before, after)| value(after) - value(before))
+                        .sum(),
+                )
+            })
+            .collect()
+    }
+}
+
+// Whether the change is made by the transaction or one of its receipts
+fn is_caused_by(cause: &StateChangeCauseView, causes: &HashSet<CryptoHash>) -> bool {
+    match cause {
+        StateChangeCauseView::TransactionProcessing { tx_hash } => causes.contains(tx_hash),
+        StateChangeCauseView::ActionReceiptProcessingStarted { receipt_hash }
+        | StateChangeCauseView::ActionReceiptGasReward { receipt_hash }
+        | StateChangeCauseView::ReceiptProcessing { receipt_hash }
+        | StateChangeCauseView::PostponedReceipt { receipt_hash } => causes.contains(receipt_hash),
+        _ => false,
+    }
+}
+
+// Query the state of the account as of the particular block, None in case the account doesn't exist at the block
+async fn view_account(
+    client: &JsonRpcClient,
+    block_hash: CryptoHash,
+    account_id: &AccountId,
+) -> Result<Option<AccountView>> {
+    let res = client
+        .call(RpcQueryRequest {
+            block_reference: BlockReference::BlockId(BlockId::Hash(block_hash)),
+            request: QueryRequest::ViewAccount {
+                account_id: account_id.clone(),
+            },
+        })
+        .await;
+    match res {
+        Ok(res) => match res.kind {
+            QueryResponseKind::ViewAccount(account) => Ok(Some(account)),
+            _ => Err(TestError::Custom(format!(
+                "Unexpected response to the view of {}",
+                account_id
+            ))),
+        },
+        Err(err)
+            if matches!(
+                err.handler_error(),
+                Some(RpcQueryError::UnknownAccount { .. })
+            ) =>
+        {
+            Ok(None)
+        }
+        Err(err) => Err(err.into()),
+    }
+}
@@ -0,0 +1,78 @@
+use super::{account_changes::AccountChanges, Result, TxResult, TxResultDetails};
+use crate::error::TestError;
+use workspaces::{network::Network, AccountId, Worker};
+
+/// This part of the TxResult is useful for checking the NEAR balance changes of the payable flows
+/// (attached deposits, refunds, gas costs)
+impl<T> TxResult<T> {
+    /// Measure the NEAR balance changes of every account which executed the transaction or its receipts
+    /// * Note: the view results are returned unchanged
+    pub async fn measure_balance_deltas(
+        mut self,
+        worker: &Worker<impl Network + 'static>,
+    ) -> Result<Self> {
+        if let TxResultDetails::Call(call_result) = &self.details {
+            self.balance_deltas = AccountChanges::take(worker, call_result)
+                .await?
+                .deltas(|account| account.amount as i128);
+        }
+        Ok(self)
+    }
+
+    /// Amount of NEAR burnt for the gas, it is paid by the signer of the transaction
+    pub fn tokens_burnt(&self) -> u128 {
+        match &self.details {
+            TxResultDetails::Call(call_result) => call_result.tokens_burnt(),
+            TxResultDetails::View(_) => 0,
+        }
+    }
+
+    /// Assert the exact balance change of the account, the mismatch is returned as the error
+    pub fn assert_balance_delta(self, account_id: &AccountId, expected: i128) -> Result<Self> {
+        let delta = self.measured_balance_delta(account_id)?;
+        if delta != expected {
+            return Err(TestError::Custom(format!(
+                "Balance delta of {} mismatch: expected {}, actual {}",
+                account_id, expected, delta
+            )));
+        }
+        Ok(self)
+    }
+
+    /// Assert the balance change of the account without the gas costs.
+    /// For the signer the burnt tokens are added back to the measured change.
+    /// Other accounts could receive the part of the burnt gas as the contract reward,
+    /// so their change could exceed the expected one by no more than the amount of burnt tokens
+    pub fn assert_balance_delta_excluding_gas(
+        self,
+        account_id: &AccountId,
+        expected: i128,
+    ) -> Result<Self> {
+        let delta = self.measured_balance_delta(account_id)?;
+        let tokens_burnt = self.tokens_burnt() as i128;
+
+        let is_signer = match &self.details {
+            TxResultDetails::Call(call_result) => call_result.signer_id() == account_id,
+            TxResultDetails::View(_) => false,
+        };
+        let matches = if is_signer {
+            delta + tokens_burnt == expected
+        } else {
+            delta >= expected && delta - expected <= tokens_burnt
+        };
+
+        if !matches {
+            return Err(TestError::Custom(format!(
+                "Balance delta of {} excluding gas mismatch: expected {}, actual {}, tokens burnt {}",
+                account_id, expected, delta, tokens_burnt
+            )));
+        }
+        Ok(self)
+    }
+
+    fn measured_balance_delta(&self, account_id: &AccountId) -> Result<i128> {
+        self.balance_deltas.get(account_id).cloned().ok_or_else(|| {
+            TestError::Custom(format!("Balance delta of {} is not measured", account_id))
+        })
+    }
+}
@@ -1,18 +1,224 @@
-use super::{FromRes, Result, TxResult, TxResultDetails};
+use super::{
+    account_changes::AccountChanges,
+    serialization::{as_string, vec_as_string},
+    FromRes, Result, TxResult, TxResultDetails,
+};
+use near_jsonrpc_client::{methods::EXPERIMENTAL_tx_status, JsonRpcClient};
+use near_primitives::views::{ActionView, ReceiptEnumView};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use workspaces::{
+    network::Network,
     result::{ExecutionFinalResult, ExecutionOutcome},
-    types::Gas,
+    types::{Balance, CryptoHash, Gas},
+    AccountId, Worker,
 };
 
+/// Execution outcome of the transaction or the receipt.
+/// It has the same data as the workspaces outcome, but could be created from the saved statistic trace
+/// * `status` - whether the outcome succeeded, the failure keeps the error message
+/// * `method_name` - function called by the transaction or the receipt, it is populated by `TxResult::measure_receipt_methods`,
+///   refunds and other receipts without the function call have no method
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutcomeDetails {
+    #[serde(with = "as_string")]
+    pub transaction_hash: CryptoHash,
+    #[serde(with = "as_string")]
+    pub block_hash: CryptoHash,
+    pub logs: Vec<String>,
+    #[serde(with = "vec_as_string")]
+    pub receipt_ids: Vec<CryptoHash>,
+    pub gas_burnt: Gas,
+    #[serde(with = "as_string")]
+    pub tokens_burnt: Balance,
+    pub executor_id: AccountId,
+    pub status: OutcomeStatus,
+    pub method_name: Option<String>,
+}
+
+/// Status of the execution outcome
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutcomeStatus {
+    Success,
+    Failure(String),
+}
+
+impl OutcomeDetails {
+    /// Id of the outcome: the hash of the transaction for the transaction outcome and the receipt id
+    /// for the receipt outcomes, workspaces stores both in the `transaction_hash` field
+    pub fn id(&self) -> &CryptoHash {
+        &self.transaction_hash
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(self.status, OutcomeStatus::Failure(_))
+    }
+}
+
+impl From<&ExecutionOutcome> for OutcomeDetails {
+    fn from(outcome: &ExecutionOutcome) -> Self {
+        Self {
+            transaction_hash: outcome.transaction_hash,
+            block_hash: outcome.block_hash,
+            logs: outcome.logs.clone(),
+            receipt_ids: outcome.receipt_ids.clone(),
+            gas_burnt: outcome.gas_burnt,
+            tokens_burnt: outcome.tokens_burnt,
+            executor_id: outcome.executor_id.clone(),
+            status: match outcome.clone().into_result() {
+                Ok(_) => OutcomeStatus::Success,
+                Err(err) => OutcomeStatus::Failure(err.to_string()),
+            },
+            method_name: None,
+        }
+    }
+}
+
 /// The struct representing the execution result data for the call method on the smart-contract
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CallResult {
     pub gas: Gas,
-    pub receipt_failures: Vec<ExecutionOutcome>,
-    pub receipt_outcomes: Vec<ExecutionOutcome>,
+    pub transaction_outcome: OutcomeDetails,
+    pub receipt_failures: Vec<OutcomeDetails>,
+    pub receipt_outcomes: Vec<OutcomeDetails>,
 }
 
 impl CallResult {
+    /// Account which signed the transaction and paid for the gas
+    pub fn signer_id(&self) -> &AccountId {
+        &self.transaction_outcome.executor_id
+    }
+
+    /// Accounts which executed the transaction or its receipts
+    pub fn executor_ids(&self) -> BTreeSet<AccountId> {
+        self.outcomes()
+            .map(|outcome| outcome.executor_id.clone())
+            .collect()
+    }
+
+    /// Outcomes of the transaction and all its receipts
+    pub fn outcomes(&self) -> impl Iterator<Item = &OutcomeDetails> {
+        std::iter::once(&self.transaction_outcome).chain(self.receipt_outcomes.iter())
+    }
+
+    /// Amount of NEAR burnt for the gas of the transaction and all its receipts,
+    /// the amount burnt by the particular receipt is available in its outcome
+    pub fn tokens_burnt(&self) -> Balance {
+        self.outcomes().map(|outcome| outcome.tokens_burnt).sum()
+    }
+
+    /// Depth of the receipt chain, the receipts created by the transaction have the depth 1
+    pub fn receipt_depth(&self) -> usize {
+        self.outcome_depths()
+            .into_iter()
+            .map(|(_, depth)| depth)
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Outcomes of the transaction and all its receipts with their depth in the receipt chain,
+    /// the transaction outcome has the depth 0.
+    /// The tree is built from the `receipt_ids` of the outcomes, the children are listed right after their parent.
+    /// * Note: the outcomes which are not referenced by any parent are listed at the end with the depth 1
+    pub fn outcome_depths(&self) -> Vec<(&OutcomeDetails, usize)> {
+        let by_id: HashMap<&CryptoHash, &OutcomeDetails> = self
+            .receipt_outcomes
+            .iter()
+            .map(|outcome| (outcome.id(), outcome))
+            .collect();
+        let mut visited: HashSet<&CryptoHash> = HashSet::new();
+        let mut depths = vec![];
+        let mut stack = vec![(&self.transaction_outcome, 0)];
+        while let Some((outcome, depth)) = stack.pop() {
+            depths.push((outcome, depth));
+            stack.extend(
+                outcome
+                    .receipt_ids
+                    .iter()
+                    .rev()
+                    .filter(|id| visited.insert(*id))
+                    .filter_map(|id| by_id.get(id))
+                    .map(|child| (*child, depth + 1)),
+            );
+        }
+        depths.extend(
+            self.receipt_outcomes
+                .iter()
+                .filter(|outcome| !visited.contains(outcome.id()))
+                .map(|outcome| (outcome, 1)),
+        );
+        depths
+    }
+
+    /// Tree of the transaction receipts, one line per outcome with the executor and the burnt gas,
+    /// the children are indented under the parent receipt and the failed receipts are marked
+    pub fn receipt_tree(&self) -> String {
+        self.outcome_depths()
+            .into_iter()
+            .map(|(outcome, depth)| {
+                let prefix = match depth {
+                    0 => String::new(),
+                    depth => format!("{}└ ", "  ".repeat(depth - 1)),
+                };
+                let failed = match outcome.is_failure() {
+                    true => " [failed]",
+                    false => "",
+                };
+                format!(
+                    "{}{} {:.3} Tgas{}",
+                    prefix,
+                    outcome.executor_id,
+                    outcome.gas_burnt as f64 / 1_000_000_000_000.,
+                    failed
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Functions called by the transaction and its receipts, keyed by the outcome id.
+    /// Workspaces outcomes don't contain the actions, so the receipts are requested
+    /// from the RPC by the transaction hash and the signer
+    pub async fn receipt_methods(
+        &self,
+        worker: &Worker<impl Network + 'static>,
+    ) -> Result<HashMap<CryptoHash, String>> {
+        let res = JsonRpcClient::connect(worker.rpc_addr())
+            .call(EXPERIMENTAL_tx_status::RpcTransactionStatusRequest {
+                transaction_info: EXPERIMENTAL_tx_status::TransactionInfo::TransactionId {
+                    hash: near_primitives::hash::CryptoHash(self.transaction_outcome.id().0),
+                    account_id: self.signer_id().clone(),
+                },
+            })
+            .await?;
+
+        let transaction = &res.final_outcome.transaction;
+        let mut methods: HashMap<CryptoHash, String> =
+            res.receipts
+                .iter()
+                .filter_map(|receipt| match &receipt.receipt {
+                    ReceiptEnumView::Action { actions, .. } => function_call(actions)
+                        .map(|method| (CryptoHash(receipt.receipt_id.0), method)),
+                    ReceiptEnumView::Data { .. } => None,
+                })
+                .collect();
+        if let Some(method) = function_call(&transaction.actions) {
+            methods.insert(CryptoHash(transaction.hash.0), method);
+        }
+        Ok(methods)
+    }
+
+    /// Storage usage changes of every account which executed the transaction or its receipts
+    pub async fn storage_deltas(
+        &self,
+        worker: &Worker<impl Network + 'static>,
+    ) -> Result<HashMap<AccountId, i64>> {
+        Ok(AccountChanges::take(worker, self)
+            .await?
+            .deltas(|account| account.storage_usage as i64))
+    }
+
     // This method is required to handle the type promise return in mutable(&mut self) methods
     pub fn value_from_res_for_promise<T: serde::de::DeserializeOwned>(
         res: &ExecutionFinalResult,
@@ -35,6 +241,25 @@ impl CallResult {
     }
 }
 
+// Name of the first function called by the actions
+fn function_call(actions: &[ActionView]) -> Option<String> {
+    actions.iter().find_map(|action| match action {
+        ActionView::FunctionCall { method_name, .. } => Some(method_name.clone()),
+        _ => None,
+    })
+}
+
+impl From<&ExecutionFinalResult> for CallResult {
+    fn from(res: &ExecutionFinalResult) -> Self {
+        Self {
+            gas: res.total_gas_burnt,
+            transaction_outcome: res.outcome().into(),
+            receipt_failures: res.receipt_failures().into_iter().map(Into::into).collect(),
+            receipt_outcomes: res.receipt_outcomes().iter().map(Into::into).collect(),
+        }
+    }
+}
+
 impl<T> FromRes<T, ExecutionFinalResult> for CallResult
 where
     T: serde::de::DeserializeOwned,
@@ -49,11 +274,19 @@ where
             func_name,
             value,
             storage_usage,
-            details: TxResultDetails::Call(CallResult {
-                gas: res.total_gas_burnt,
-                receipt_failures: res.receipt_failures().into_iter().cloned().collect(),
-                receipt_outcomes: res.receipt_outcomes().to_vec(),
-            }),
+            storage_deltas: Default::default(),
+            balance_deltas: Default::default(),
+            retries: 0,
+            started_at: None,
+            finished_at: None,
+            contract_id: None,
+            args: None,
+            deposit: None,
+            caller: Some(res.outcome().executor_id.clone()),
+            tx_hash: Some(res.outcome().transaction_hash),
+            block_hash: Some(res.outcome().block_hash),
+            block_height: None,
+            details: TxResultDetails::Call(CallResult::from(&res)),
         })
     }
 
@@ -1,11 +1,31 @@
+pub mod account_changes;
+pub mod balance_deltas;
 pub mod call_result;
 pub mod log_parser;
+pub(crate) mod serialization;
 pub mod view_result;
 
-pub use self::{call_result::CallResult, view_result::ViewResult};
+pub use self::{
+    call_result::{CallResult, OutcomeDetails, OutcomeStatus},
+    view_result::ViewResult,
+};
 use crate::{
     error::Result,
-    statistic::statistic_consumer::{Statistic, StatisticConsumer},
+    statistic::{
+        global_registry::GlobalRegistry,
+        statistic_consumer::{Statistic, StatisticConsumer},
+    },
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    time::{Duration, SystemTime},
+};
+use workspaces::{
+    network::Network,
+    result::{ExecutionFinalResult, ViewResultDetails},
+    types::{Balance, CryptoHash},
+    AccountId, Worker,
 };
 
 #[derive(Debug, Clone)]
@@ -13,10 +33,34 @@ pub struct TxResult<T> {
     pub func_name: String,
     pub value: T,
     pub storage_usage: Option<i64>,
+    // storage usage changes of every account which executed the receipts, populated by `measure_storage_deltas`
+    pub storage_deltas: HashMap<AccountId, i64>,
+    // NEAR balance changes of every account which executed the receipts, populated by `measure_balance_deltas`
+    pub balance_deltas: HashMap<AccountId, i128>,
+    // number of the RPC call retries made according to the retry policy
+    pub retries: u32,
+    // wall-clock time when the call was started and finished, populated by the generated functions
+    pub started_at: Option<SystemTime>,
+    pub finished_at: Option<SystemTime>,
+    // contract, serialized JSON arguments and attached deposit of the call, populated by the generated functions
+    pub contract_id: Option<AccountId>,
+    pub args: Option<String>,
+    pub deposit: Option<Balance>,
+    // signer of the transaction, it is known only for the calls
+    pub caller: Option<AccountId>,
+    // transaction and the block which included it, they are known only for the calls
+    pub tx_hash: Option<CryptoHash>,
+    pub block_hash: Option<CryptoHash>,
+    // height of the block which included the transaction, populated by `measure_block_height`
+    pub block_height: Option<u64>,
     pub details: TxResultDetails,
 }
 
-#[derive(Debug, Clone)]
+// most of the results are the calls, so the call details are not boxed
+// the details are serialized as `{"view": {...}}` or `{"call": {...}}`
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum TxResultDetails {
     View(ViewResult),
     Call(CallResult),
@@ -28,6 +72,20 @@ impl Default for TxResultDetails {
     }
 }
 
+impl From<&ExecutionFinalResult> for TxResultDetails {
+    fn from(res: &ExecutionFinalResult) -> Self {
+        Self::Call(CallResult::from(res))
+    }
+}
+
+impl From<&ViewResultDetails> for TxResultDetails {
+    fn from(res: &ViewResultDetails) -> Self {
+        Self::View(ViewResult {
+            logs: res.logs.clone(),
+        })
+    }
+}
+
 pub trait FromRes<T, R> {
     fn value_from_res(res: &R) -> Result<T>;
     fn from_res(
@@ -38,11 +96,103 @@ pub trait FromRes<T, R> {
     ) -> Result<TxResult<T>>;
 }
 
+impl<T> TxResult<T> {
+    /// Set the number of the RPC call retries made before the successful attempt
+    pub fn with_retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Set the wall-clock time of the call start and finish
+    pub fn with_timing(mut self, started_at: SystemTime, finished_at: SystemTime) -> Self {
+        self.started_at = Some(started_at);
+        self.finished_at = Some(finished_at);
+        self
+    }
+
+    /// Set the contract, serialized JSON arguments and attached deposit of the call
+    /// * `deposit` - None for the view calls
+    pub fn with_call_info(
+        mut self,
+        contract_id: &AccountId,
+        args: String,
+        deposit: Option<Balance>,
+    ) -> Self {
+        self.contract_id = Some(contract_id.clone());
+        self.args = Some(args);
+        self.deposit = deposit;
+        self
+    }
+
+    /// Measure the height of the block which included the transaction
+    /// * Note: the view results are returned unchanged
+    pub async fn measure_block_height(
+        mut self,
+        worker: &Worker<impl Network + 'static>,
+    ) -> Result<Self> {
+        if let Some(block_hash) = self.block_hash {
+            self.block_height = Some(worker.view_block().block_hash(block_hash).await?.height());
+        }
+        Ok(self)
+    }
+
+    /// Wall-clock duration of the call, including the retries
+    pub fn duration(&self) -> Option<Duration> {
+        duration_between(self.started_at, self.finished_at)
+    }
+
+    /// Measure the names of the functions called by the transaction and its receipts,
+    /// e.g. `ft_transfer_call` -> `ft_on_transfer` -> `ft_resolve_transfer`
+    /// * Note: the view results are returned unchanged
+    pub async fn measure_receipt_methods(
+        mut self,
+        worker: &Worker<impl Network + 'static>,
+    ) -> Result<Self> {
+        if let TxResultDetails::Call(call_result) = &mut self.details {
+            let methods = call_result.receipt_methods(worker).await?;
+            for outcome in std::iter::once(&mut call_result.transaction_outcome)
+                .chain(call_result.receipt_outcomes.iter_mut())
+                .chain(call_result.receipt_failures.iter_mut())
+            {
+                outcome.method_name = methods.get(outcome.id()).cloned();
+            }
+        }
+        Ok(self)
+    }
+
+    /// Populate the global registry with the statistic of the call, in case the registry is enabled.
+    /// It is called by the generated functions
+    pub fn record_global_statistic(self) -> Self {
+        if GlobalRegistry::is_enabled() {
+            GlobalRegistry::record(&Statistic::from(&self));
+        }
+        self
+    }
+
+    /// Measure the storage usage changes of every account which executed the transaction or its receipts
+    /// (caller, contract, token contracts, receivers, etc.)
+    /// * Note: the view results are returned unchanged
+    pub async fn measure_storage_deltas(
+        mut self,
+        worker: &Worker<impl Network + 'static>,
+    ) -> Result<Self> {
+        if let TxResultDetails::Call(call_result) = &self.details {
+            self.storage_deltas = call_result.storage_deltas(worker).await?;
+        }
+        Ok(self)
+    }
+}
+
 impl<T> TxResult<T>
 where
     T: Clone,
 {
-    pub fn populate_statistic(self, consumers: &mut [&mut Box<dyn StatisticConsumer>]) -> Self {
+    /// Populate the consumers with the statistic of the call,
+    /// consumers could be boxed (`Box<dyn StatisticConsumer>`), shared (`SharedConsumer`) or plain ones
+    pub fn populate_statistic<C: StatisticConsumer + ?Sized>(
+        self,
+        consumers: &mut [&mut C],
+    ) -> Self {
         for consumer in consumers.iter_mut() {
             consumer.consume_statistic(&Statistic::from(self.clone()));
         }
@@ -50,9 +200,9 @@ where
     }
 
     #[allow(dead_code)]
-    pub fn process_statistics<const N: usize>(
+    pub fn process_statistics<C: StatisticConsumer + ?Sized>(
         self,
-        consumers: &mut [&mut Box<dyn StatisticConsumer>],
+        consumers: &mut [&mut C],
     ) -> String {
         let mut result = String::new();
 
@@ -65,18 +215,20 @@ where
     }
 }
 
+// Duration between the timestamps, it is zero in case the clock went backwards
+pub(crate) fn duration_between(
+    started_at: Option<SystemTime>,
+    finished_at: Option<SystemTime>,
+) -> Option<Duration> {
+    Some(finished_at?.duration_since(started_at?).unwrap_or_default())
+}
+
 pub trait IntoMutRefs<T, const N: usize> {
     fn into_refs(&mut self) -> [&mut T; N];
 }
 
-impl<const N: usize> IntoMutRefs<Box<dyn StatisticConsumer>, N>
-    for [Box<dyn StatisticConsumer>; N]
-{
-    fn into_refs(&mut self) -> [&mut Box<dyn StatisticConsumer>; N] {
-        self.iter_mut()
-            .map(|c| c)
-            .collect::<Vec<_>>()
-            .try_into()
-            .unwrap()
+impl<T, const N: usize> IntoMutRefs<T, N> for [T; N] {
+    fn into_refs(&mut self) -> [&mut T; N] {
+        self.each_mut()
     }
 }
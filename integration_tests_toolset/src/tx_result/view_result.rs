@@ -1,9 +1,10 @@
 use super::{FromRes, TxResult, TxResultDetails};
 use crate::error::Result;
+use serde::{Deserialize, Serialize};
 use workspaces::result::ViewResultDetails;
 
 /// The struct representing the execution result data for the view method on the smart-contract
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ViewResult {
     pub logs: Vec<String>,
 }
@@ -23,6 +24,18 @@ where
             func_name,
             value,
             storage_usage,
+            storage_deltas: Default::default(),
+            balance_deltas: Default::default(),
+            retries: 0,
+            started_at: None,
+            finished_at: None,
+            contract_id: None,
+            args: None,
+            deposit: None,
+            caller: None,
+            tx_hash: None,
+            block_hash: None,
+            block_height: None,
             details: TxResultDetails::View(ViewResult { logs: res.logs }),
         })
     }
@@ -1,39 +1,127 @@
 use futures::{future::try_join_all, try_join, Future, FutureExt, TryFutureExt};
 use integration_tests_toolset::{
     error::{self, TestError},
-    statistic::statistic_consumer::Statistic,
-    tx_result::TxResult,
+    statistic::{failed_call::capture_failed_call, statistic_consumer::Statistic},
+    tx_result::{TxResult, TxResultDetails},
 };
-use std::pin::Pin;
+use std::{pin::Pin, time::SystemTime};
+use workspaces::{network::Sandbox, Worker};
 
 type ExecutionFuture<'a> = Pin<Box<dyn Future<Output = error::Result<Statistic>> + Send + 'a>>;
 type ExecutionFutureUnit<'a> = Pin<Box<dyn Future<Output = error::Result<()>> + Send + 'a>>;
 type CallFuture<'a, T> = Pin<Box<dyn Future<Output = error::Result<TxResult<T>>> + Send + 'a>>;
 
+/// Name of the failed operation in the statistic, which was created without the function name
+pub const UNKNOWN_OPERATION: &str = "<unknown>";
+
 /// Types of execution operations
 pub enum ExecutionOperation<'a> {
     /// Sub batch for nested blocks of operations
     SubBatch(Batch<'a>),
     /// Contract operations, generated by tests bindgen macro
-    ContractOperation(ExecutionFuture<'a>),
+    /// along with the explicit name of the function, which is used for the statistic of the failed operation
+    ContractOperation(ExecutionFuture<'a>, Option<String>),
     /// Unit operations, that don't return statistic for util operations
     /// like checks, fast forward, asserts, etc.
     UnitOperation(ExecutionFutureUnit<'a>),
+    /// Operation with the label, which is attached to all statistic produced by it
+    Labeled(Box<ExecutionOperation<'a>>, String),
 }
 
 impl<'a> ExecutionOperation<'a> {
+    /// Attach the label to the statistic of the operation, e.g. to distinguish the steps of the scenario
+    /// which call the same function. In case of the sub batch the label is attached to all its operations
+    /// * Note: several labels could be attached to the same operation
+    pub fn label(self, label: &str) -> Self {
+        ExecutionOperation::Labeled(Box::new(self), label.to_owned())
+    }
+
     pub async fn run(self) -> error::Result<Vec<Statistic>> {
         let res = match self {
             ExecutionOperation::SubBatch(block) => block.run().await?,
-            ExecutionOperation::ContractOperation(op) => vec![op.await?],
+            ExecutionOperation::ContractOperation(op, _) => vec![op.await?],
             ExecutionOperation::UnitOperation(op) => {
                 op.await?;
                 vec![]
             }
+            ExecutionOperation::Labeled(op, label) => with_label(Box::pin(op.run()).await?, &label),
         };
 
         Ok(res)
     }
+
+    /// Run operation and mark produced statistic with the height of the block reached after the operation
+    /// and the storage usage changes of all accounts which executed the receipts.
+    /// In the continue-on-error mode the failed contract operation produces the statistic with the error
+    /// and the time of the operation
+    /// * Note: sub batches inherit the worker for block tracking and the continue-on-error mode from the parent batch
+    async fn run_tracked(
+        self,
+        worker: Option<Worker<Sandbox>>,
+        continue_on_error: bool,
+    ) -> error::Result<Vec<Statistic>> {
+        let mut res = match (self, &worker) {
+            (ExecutionOperation::SubBatch(mut block), worker) => {
+                block.continue_on_error |= continue_on_error;
+                match worker {
+                    Some(worker) if block.worker.is_none() => {
+                        block.track_blocks(worker).run().await?
+                    }
+                    _ => block.run().await?,
+                }
+            }
+            (ExecutionOperation::ContractOperation(op, func_name), _) if continue_on_error => {
+                let started_at = SystemTime::now();
+                match capture_failed_call(op).await {
+                    (Ok(stat), _) => vec![stat],
+                    (Err(err), failed_call) => {
+                        // the failed generated function records its statistic with the function name
+                        let stat = failed_call.unwrap_or_else(|| {
+                            Statistic::failed(
+                                func_name.as_deref().unwrap_or(UNKNOWN_OPERATION),
+                                &err,
+                            )
+                        });
+                        vec![Statistic {
+                            func_name: func_name.unwrap_or(stat.func_name),
+                            started_at: Some(started_at),
+                            finished_at: Some(SystemTime::now()),
+                            ..stat
+                        }]
+                    }
+                }
+            }
+            (ExecutionOperation::Labeled(op, label), worker) => with_label(
+                Box::pin(op.run_tracked(worker.clone(), continue_on_error)).await?,
+                &label,
+            ),
+            (op, _) => op.run().await?,
+        };
+
+        if let Some(worker) = worker {
+            let block_height = worker.view_block().await?.height();
+            for stat in res
+                .iter_mut()
+                .filter(|stat| stat.reached_block_height.is_none())
+            {
+                stat.reached_block_height = Some(block_height);
+                if let TxResultDetails::Call(call_result) = &stat.details {
+                    if stat.storage_deltas.is_empty() {
+                        stat.storage_deltas = call_result.storage_deltas(&worker).await?;
+                    }
+                }
+            }
+        }
+
+        Ok(res)
+    }
+}
+
+fn with_label(stats: Vec<Statistic>, label: &str) -> Vec<Statistic> {
+    stats
+        .into_iter()
+        .map(|stat| stat.with_label(label))
+        .collect()
 }
 
 /// Main building block for scenario toolset
@@ -51,9 +139,19 @@ impl<'a> ExecutionOperation<'a> {
 ///
 /// Statistics can be processed with `StatisticConsumers` to get specific statistics
 /// for example Gas consumption or contract storage changes
+///
+/// By default the first failed operation aborts the batch. With `continue_on_error` method
+/// the failed contract operations are recorded into the `Statistic::error` and the batch is continued,
+/// it is useful for the load tests along with the `ErrorRate` consumer
+///
+/// With `track_blocks` method batch records the height of the block reached after each operation
+/// into the `Statistic::reached_block_height`, so the state as of any earlier step can be queried with generated `_at` view functions.
+/// Also the storage usage changes of every account touched by the operation are measured, even for the concurrent operations
 pub struct Batch<'a> {
     pub chain: Vec<ExecutionOperation<'a>>,
     pub concurrent: Vec<ExecutionOperation<'a>>,
+    pub worker: Option<Worker<Sandbox>>,
+    pub continue_on_error: bool,
 }
 
 impl<'a> Batch<'a> {
@@ -61,19 +159,42 @@ impl<'a> Batch<'a> {
         Self {
             chain: vec![],
             concurrent: vec![],
+            worker: None,
+            continue_on_error: false,
         }
     }
+
+    /// Record the failed contract operations into the statistic instead of aborting the batch
+    /// * Note: failed unit operations still abort the batch
+    pub fn continue_on_error(mut self) -> Self {
+        self.continue_on_error = true;
+        self
+    }
+
+    /// Record the height of the block reached after each operation of the batch and sub batches
+    /// along with the storage usage changes of the accounts touched by the operation
+    pub fn track_blocks(mut self, worker: &Worker<Sandbox>) -> Self {
+        self.worker = Some(worker.clone());
+        self
+    }
 }
 
 impl<'a> Batch<'a> {
     pub fn run(self) -> Pin<Box<dyn Future<Output = error::Result<Vec<Statistic>>> + Send + 'a>> {
-        let async_block = move || async {
+        let Batch {
+            chain,
+            concurrent,
+            worker,
+            continue_on_error,
+        } = self;
+
+        let async_block = move || async move {
             let join_result = try_join!(
                 try_join_all(
                     [|| async {
                         let mut statistics = vec![];
-                        for op in self.chain.into_iter() {
-                            let res = op.run().await?;
+                        for op in chain.into_iter() {
+                            let res = op.run_tracked(worker.clone(), continue_on_error).await?;
                             res.into_iter().for_each(|stat| statistics.push(stat));
                         }
                         Ok::<Vec<Statistic>, TestError>(statistics)
@@ -81,7 +202,11 @@ impl<'a> Batch<'a> {
                     .into_iter()
                     .map(|a| a())
                 ),
-                try_join_all(self.concurrent.into_iter().map(|op| op.run())),
+                try_join_all(
+                    concurrent
+                        .into_iter()
+                        .map(|op| op.run_tracked(worker.clone(), continue_on_error))
+                ),
             );
             join_result
         };
@@ -129,7 +254,7 @@ impl<'a> From<Batch<'a>> for ExecutionOperation<'a> {
 
 impl<'a> From<ExecutionFuture<'a>> for ExecutionOperation<'a> {
     fn from(value: ExecutionFuture<'a>) -> Self {
-        ExecutionOperation::ContractOperation(value)
+        ExecutionOperation::ContractOperation(value, None)
     }
 }
 
@@ -142,18 +267,28 @@ impl<'a> From<ExecutionFutureUnit<'a>> for ExecutionOperation<'a> {
 impl<'a, T: 'a> From<CallFuture<'a, T>> for ExecutionOperation<'a> {
     fn from(value: CallFuture<'a, T>) -> Self {
         let res = value.map(|res| res.map(|tx| Statistic::from(tx))).boxed();
-        ExecutionOperation::ContractOperation(res)
+        ExecutionOperation::ContractOperation(res, None)
     }
 }
 
 /// Converter for generated contract operation to execution operation
+/// * Note: the function name of the failed operation is recorded by the generated functions,
+///   for the operations which fail before the call `make_named_op` should be used
 pub fn make_op<'a, T>(
     input: impl Future<Output = error::Result<TxResult<T>>> + Send + 'a,
 ) -> ExecutionOperation<'a> {
-    input
-        .map(|res| res.map(|tx| Statistic::from(tx)))
-        .boxed()
-        .into()
+    ExecutionOperation::ContractOperation(input.map(|res| res.map(Statistic::from)).boxed(), None)
+}
+
+/// Converter for contract operation to execution operation with the explicit function name
+pub fn make_named_op<'a, T>(
+    func_name: &str,
+    input: impl Future<Output = error::Result<TxResult<T>>> + Send + 'a,
+) -> ExecutionOperation<'a> {
+    ExecutionOperation::ContractOperation(
+        input.map(|res| res.map(Statistic::from)).boxed(),
+        Some(func_name.to_owned()),
+    )
 }
 
 /// Converter for custom operations like asserts, checks, even closures
@@ -0,0 +1,102 @@
+use integration_tests_toolset::{pending_tx::signer::KeySigner, print_log};
+use owo_colors::OwoColorize;
+use workspaces::{
+    network::Sandbox,
+    types::{AccessKey, Balance, KeyType, SecretKey},
+    Account, AccountId, Worker,
+};
+
+/// Function-call access key description
+/// * `receiver_id` - the only contract, which could be called with the key
+/// * `method_names` - allowed methods list, empty list means that all methods are allowed
+/// * `allowance` - amount of NEAR which could be spent on the gas fees, None means unlimited allowance
+#[derive(Debug, Clone)]
+pub struct FunctionCallKey {
+    pub receiver_id: AccountId,
+    pub method_names: Vec<String>,
+    pub allowance: Option<Balance>,
+}
+
+impl FunctionCallKey {
+    pub fn new(receiver_id: AccountId) -> Self {
+        Self {
+            receiver_id,
+            method_names: vec![],
+            allowance: None,
+        }
+    }
+
+    /// Restrict the key to the provided methods list
+    pub fn methods(mut self, method_names: &[&str]) -> Self {
+        self.method_names = method_names.iter().map(|name| name.to_string()).collect();
+        self
+    }
+
+    /// Limit the amount of NEAR which could be spent on the gas fees with the key
+    pub fn allowance(mut self, allowance: Balance) -> Self {
+        self.allowance = Some(allowance);
+        self
+    }
+}
+
+impl From<&FunctionCallKey> for AccessKey {
+    fn from(key: &FunctionCallKey) -> Self {
+        AccessKey::function_call_access(
+            &key.receiver_id,
+            &key.method_names
+                .iter()
+                .map(|name| name.as_str())
+                .collect::<Vec<_>>(),
+            key.allowance,
+        )
+    }
+}
+
+/// Create new function-call access key and attach it to the test account
+/// Returns the signer, which should be passed as caller to the generated contract functions
+/// to sign transactions with the created key
+pub async fn add_function_call_key(
+    worker: &Worker<Sandbox>,
+    account: &Account,
+    key: &FunctionCallKey,
+) -> anyhow::Result<KeySigner> {
+    let signer = add_access_key(worker, account, key.into()).await?;
+
+    print_log!(
+        "Added function-call key for {} to call {}",
+        account.id().green(),
+        key.receiver_id.blue()
+    );
+
+    Ok(signer)
+}
+
+/// Create new full access key and attach it to the test account
+pub async fn add_full_access_key(
+    worker: &Worker<Sandbox>,
+    account: &Account,
+) -> anyhow::Result<KeySigner> {
+    let signer = add_access_key(worker, account, AccessKey::full_access()).await?;
+
+    print_log!("Added full access key for {}", account.id().green());
+
+    Ok(signer)
+}
+
+/// Generate random key, attach it to the account with the provided permissions
+async fn add_access_key(
+    worker: &Worker<Sandbox>,
+    account: &Account,
+    access_key: AccessKey,
+) -> anyhow::Result<KeySigner> {
+    let secret_key = SecretKey::from_random(KeyType::ED25519);
+
+    account
+        .batch(account.id())
+        .add_key(secret_key.public_key(), access_key)
+        .transact()
+        .await?
+        .into_result()?;
+
+    Ok(KeySigner::new(worker, account.id().clone(), secret_key))
+}
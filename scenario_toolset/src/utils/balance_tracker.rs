@@ -0,0 +1,218 @@
+use super::token_info::TokenInfo;
+use anyhow::anyhow;
+use integration_tests_toolset::statistic::{
+    mode_printer::ModePrinter,
+    report::{render_report, ReportCell, ReportFormat, ReportTable},
+    statistic_printer::StatisticPrinter,
+};
+use owo_colors::OwoColorize;
+use std::collections::HashMap;
+use test_token::TokenContractTest;
+use workspaces::AccountId;
+
+/// Tracker of the fungible token balances of the set of accounts
+/// * `token_infos` - descriptions of the tokens, which were used in `initialize_context`
+/// * `token_contracts` - token contracts returned by `initialize_context`
+/// * `accounts` - accounts which balances should be tracked
+///
+/// Tracker takes the snapshot of the balances with `snapshot` method,
+/// after that the changes could be checked with `assert_deltas` method.
+/// Expected deltas are defined in human units, for example `(&maker, &eth(), "-1.5")`.
+/// Balances of the tracked accounts which are not mentioned in the expected deltas should stay unchanged.
+/// The deltas measured with `measure_deltas` are printed in human units as the statistic report
+pub struct BalanceTracker<'a> {
+    tokens: Vec<(&'a TokenInfo, &'a TokenContractTest)>,
+    accounts: Vec<AccountId>,
+    balances: HashMap<(AccountId, AccountId), u128>,
+    measured_deltas: HashMap<(AccountId, AccountId), i128>,
+    mode_printer: ModePrinter,
+    report_format: ReportFormat,
+}
+
+impl<'a> BalanceTracker<'a> {
+    pub fn new(
+        token_infos: &'a [TokenInfo],
+        token_contracts: &'a [TokenContractTest],
+        accounts: &[AccountId],
+    ) -> anyhow::Result<Self> {
+        let tokens = token_infos
+            .iter()
+            .map(|token_info| {
+                token_contracts
+                    .iter()
+                    .find(|token| token.contract.id() == &token_info.account_id)
+                    .map(|token| (token_info, token))
+                    .ok_or_else(|| anyhow!("Token contract {} is not provided", token_info))
+            })
+            .collect::<anyhow::Result<_>>()?;
+
+        Ok(Self {
+            tokens,
+            accounts: accounts.to_vec(),
+            balances: HashMap::new(),
+            measured_deltas: HashMap::new(),
+            mode_printer: ModePrinter::default(),
+            report_format: ReportFormat::default(),
+        })
+    }
+
+    pub fn with_mode_printer(mut self, mode_printer: ModePrinter) -> Self {
+        self.mode_printer = mode_printer;
+        self
+    }
+
+    pub fn with_report_format(mut self, report_format: ReportFormat) -> Self {
+        self.report_format = report_format;
+        self
+    }
+
+    /// Remember the current balances, the following deltas are calculated relative to them
+    pub async fn snapshot(&mut self) -> anyhow::Result<()> {
+        self.balances = self.current_balances().await?;
+        Ok(())
+    }
+
+    /// Changes of the balances since the last snapshot, keyed by (token, account)
+    pub async fn deltas(&self) -> anyhow::Result<HashMap<(AccountId, AccountId), i128>> {
+        Ok(self
+            .current_balances()
+            .await?
+            .into_iter()
+            .map(|(key, balance)| {
+                let before = self.balances.get(&key).cloned().unwrap_or_default();
+                (key, balance as i128 - before as i128)
+            })
+            .collect())
+    }
+
+    /// Remember the changes of the balances since the last snapshot for the report
+    pub async fn measure_deltas(&mut self) -> anyhow::Result<()> {
+        self.measured_deltas = self.deltas().await?;
+        Ok(())
+    }
+
+    /// Assert the changes of the balances since the last snapshot
+    /// Returns the error with the table of mismatches (in the report format of the tracker)
+    /// in case any delta differs from the expected one
+    pub async fn assert_deltas(
+        &self,
+        expected: &[(&AccountId, &TokenInfo, &str)],
+    ) -> anyhow::Result<()> {
+        let mut expected_deltas = HashMap::new();
+        for (account_id, token_info, amount) in expected.iter() {
+            let key = (token_info.account_id.clone(), (*account_id).clone());
+            if !self.balances.contains_key(&key) {
+                return Err(anyhow!(
+                    "Balance of {} in {} is not tracked",
+                    account_id,
+                    token_info
+                ));
+            }
+            *expected_deltas.entry(key).or_insert(0) += parse_delta(token_info, amount)?;
+        }
+
+        let deltas = self.deltas().await?;
+
+        let mut table = ReportTable::new(
+            "Balance deltas mismatch",
+            ["Account", "Token", "Expected", "Actual"]
+                .iter()
+                .map(|title| title.to_string())
+                .collect(),
+        );
+        for (token_info, _) in self.tokens.iter() {
+            for account_id in self.accounts.iter() {
+                let key = (token_info.account_id.clone(), account_id.clone());
+                let expected = expected_deltas.get(&key).cloned().unwrap_or_default();
+                let actual = deltas.get(&key).cloned().unwrap_or_default();
+                if expected != actual {
+                    let (expected, actual) = (
+                        format_delta(token_info, expected),
+                        format_delta(token_info, actual),
+                    );
+                    table.add_row(vec![
+                        ReportCell::new(account_id.as_str().green().bold(), account_id.as_str()),
+                        ReportCell::new(
+                            token_info.ticker.blue().bold(),
+                            token_info.ticker.as_str(),
+                        ),
+                        ReportCell::new(expected.bright_magenta().bold(), expected.as_str()),
+                        ReportCell::new(actual.red().bold(), actual.as_str()),
+                    ]);
+                }
+            }
+        }
+
+        if !table.rows.is_empty() {
+            return Err(anyhow!(
+                "{}",
+                render_report(&[table], self.report_format, true)
+            ));
+        }
+        Ok(())
+    }
+
+    async fn current_balances(&self) -> anyhow::Result<HashMap<(AccountId, AccountId), u128>> {
+        let mut balances = HashMap::new();
+        for (token_info, token) in self.tokens.iter() {
+            for account_id in self.accounts.iter() {
+                let balance = token.ft_balance_of(account_id.clone()).await?.value.0;
+                balances.insert((token_info.account_id.clone(), account_id.clone()), balance);
+            }
+        }
+        Ok(balances)
+    }
+}
+
+// Parse the signed amount in human units
+fn parse_delta(token_info: &TokenInfo, amount: &str) -> anyhow::Result<i128> {
+    match amount.trim().strip_prefix('-') {
+        Some(amount) => Ok(-(token_info.parse_amount(amount)?.amount() as i128)),
+        None => Ok(token_info
+            .parse_amount(amount.trim_start_matches('+'))?
+            .amount() as i128),
+    }
+}
+
+// Format the signed amount in human units
+fn format_delta(token_info: &TokenInfo, delta: i128) -> String {
+    let sign = if delta < 0 { "-" } else { "" };
+    format!("{}{}", sign, token_info.amount(delta.unsigned_abs()))
+}
+
+impl<'a> StatisticPrinter for BalanceTracker<'a> {
+    fn get_printer_mode(&self) -> &ModePrinter {
+        &self.mode_printer
+    }
+
+    fn get_report_format(&self) -> ReportFormat {
+        self.report_format
+    }
+
+    // Raw deltas are stored as strings, since they don't fit into the JSON numbers
+    fn report_tables(&self) -> Vec<ReportTable> {
+        let mut table = ReportTable::new(
+            "Token balance deltas",
+            ["Account", "Token", "Delta", "Raw delta"]
+                .iter()
+                .map(|title| title.to_string())
+                .collect(),
+        );
+        for (token_info, _) in self.tokens.iter() {
+            for account_id in self.accounts.iter() {
+                let key = (token_info.account_id.clone(), account_id.clone());
+                let Some(delta) = self.measured_deltas.get(&key) else {
+                    continue;
+                };
+                let formatted = format_delta(token_info, *delta);
+                table.add_row(vec![
+                    ReportCell::new(account_id.as_str().green().bold(), account_id.as_str()),
+                    ReportCell::new(token_info.ticker.blue().bold(), token_info.ticker.as_str()),
+                    ReportCell::new(formatted.bright_magenta().bold(), formatted.as_str()),
+                    ReportCell::new(delta, delta.to_string()),
+                ]);
+            }
+        }
+        vec![table]
+    }
+}
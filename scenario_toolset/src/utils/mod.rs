@@ -1,3 +1,6 @@
+pub mod access_key;
+pub mod balance_tracker;
+pub mod token_amount;
 pub mod token_info;
 
 use once_cell::sync::Lazy;
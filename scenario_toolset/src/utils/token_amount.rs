@@ -0,0 +1,170 @@
+use super::token_info::TokenInfo;
+use near_sdk::json_types::U128;
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+};
+use workspaces::{types::Balance, AccountId};
+
+/// Amount of the fungible token, which is aware of the token decimals
+/// Raw amount is stored along with the token description, so it could be printed in human units, e.g. `1.5 ETH`
+/// * Note: arithmetic and comparison are defined only for the amounts of the same token
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenAmount {
+    token_id: AccountId,
+    ticker: String,
+    decimals: u8,
+    amount: Balance,
+}
+
+impl TokenAmount {
+    pub fn new(token_info: &TokenInfo, amount: Balance) -> Self {
+        Self {
+            token_id: token_info.account_id.clone(),
+            ticker: token_info.ticker.clone(),
+            decimals: token_info.decimals,
+            amount,
+        }
+    }
+
+    /// Parse the amount in human units, e.g. "1.5"
+    pub fn parse(token_info: &TokenInfo, amount: &str) -> anyhow::Result<Self> {
+        Ok(Self::new(token_info, token_info.parse(amount)?))
+    }
+
+    pub fn token_id(&self) -> &AccountId {
+        &self.token_id
+    }
+
+    /// Raw amount in the smallest token units
+    pub fn amount(&self) -> Balance {
+        self.amount
+    }
+
+    /// Amount in human units without the ticker, e.g. "1.5"
+    pub fn to_decimal_string(&self) -> String {
+        let unit = 10u128.pow(self.decimals as u32);
+        let fraction = format!(
+            "{:0width$}",
+            self.amount % unit,
+            width = self.decimals as usize
+        );
+        let fraction = fraction.trim_end_matches('0');
+
+        if fraction.is_empty() {
+            format!("{}", self.amount / unit)
+        } else {
+            format!("{}.{}", self.amount / unit, fraction)
+        }
+    }
+
+    /// Returns None in case of the overflow or different tokens
+    pub fn checked_add(&self, other: &TokenAmount) -> Option<TokenAmount> {
+        self.same_token(other)?;
+        self.amount
+            .checked_add(other.amount)
+            .map(|amount| self.with_amount(amount))
+    }
+
+    /// Returns None in case of the underflow or different tokens
+    pub fn checked_sub(&self, other: &TokenAmount) -> Option<TokenAmount> {
+        self.same_token(other)?;
+        self.amount
+            .checked_sub(other.amount)
+            .map(|amount| self.with_amount(amount))
+    }
+
+    /// Multiply the amount by the ratio `numerator / denominator` rounding down
+    /// Returns None in case of the overflow or zero denominator
+    pub fn checked_mul_ratio(&self, numerator: u128, denominator: u128) -> Option<TokenAmount> {
+        self.amount
+            .checked_mul(numerator)?
+            .checked_div(denominator)
+            .map(|amount| self.with_amount(amount))
+    }
+
+    /// Absolute difference between the amounts, returns None for different tokens
+    pub fn abs_diff(&self, other: &TokenAmount) -> Option<TokenAmount> {
+        self.same_token(other)?;
+        Some(self.with_amount(self.amount.abs_diff(other.amount)))
+    }
+
+    /// Check whether the amounts differ by no more than the tolerance
+    pub fn is_close_to(&self, other: &TokenAmount, tolerance: &TokenAmount) -> bool {
+        matches!(
+            self.abs_diff(other)
+                .and_then(|diff| diff.partial_cmp(tolerance)),
+            Some(Ordering::Less | Ordering::Equal)
+        )
+    }
+
+    fn with_amount(&self, amount: Balance) -> TokenAmount {
+        TokenAmount {
+            amount,
+            ..self.clone()
+        }
+    }
+
+    fn same_token(&self, other: &TokenAmount) -> Option<()> {
+        (self.token_id == other.token_id).then_some(())
+    }
+}
+
+impl PartialOrd for TokenAmount {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.same_token(other)?;
+        self.amount.partial_cmp(&other.amount)
+    }
+}
+
+impl Display for TokenAmount {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.to_decimal_string(), self.ticker)
+    }
+}
+
+impl From<TokenAmount> for U128 {
+    fn from(value: TokenAmount) -> Self {
+        U128(value.amount)
+    }
+}
+
+impl From<&TokenAmount> for U128 {
+    fn from(value: &TokenAmount) -> Self {
+        U128(value.amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::U128;
+    use crate::utils::token_info::{eth, usdc};
+
+    #[test]
+    fn test_token_amount_usage() -> anyhow::Result<()> {
+        let amount = usdc().parse_amount("1.5")?;
+        assert_eq!(amount.amount(), 1_500_000);
+        assert_eq!(amount.to_string(), "1.5 USDC");
+
+        let fee = amount.checked_mul_ratio(3, 1000).unwrap();
+        assert_eq!(fee.to_string(), "0.0045 USDC");
+        assert_eq!(
+            amount.checked_sub(&fee).unwrap().checked_add(&fee),
+            Some(amount.clone())
+        );
+        assert_eq!(fee.checked_sub(&amount), None);
+
+        // amounts of different tokens could not be combined or compared
+        assert_eq!(amount.checked_add(&eth().parse_amount("1.5")?), None);
+        assert_eq!(amount.partial_cmp(&eth().parse_amount("1.5")?), None);
+
+        let tolerance = usdc().parse_amount("0.01")?;
+        assert!(amount.is_close_to(&usdc().parse_amount("1.505")?, &tolerance));
+        assert!(!amount.is_close_to(&usdc().parse_amount("1.52")?, &tolerance));
+
+        let raw: U128 = amount.into();
+        assert_eq!(raw.0, 1_500_000);
+
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use super::token_amount::TokenAmount;
 use anyhow::anyhow;
 use once_cell::sync::Lazy;
 use std::fmt::{self, Display, Formatter};
@@ -33,6 +34,16 @@ impl TokenInfo {
         Ok(amount.parse()?)
     }
 
+    /// Raw amount of the token bound to its description
+    pub fn amount(&self, amount: Balance) -> TokenAmount {
+        TokenAmount::new(self, amount)
+    }
+
+    /// Parse the amount in human units bound to the token description
+    pub fn parse_amount(&self, amount: &str) -> anyhow::Result<TokenAmount> {
+        TokenAmount::parse(self, amount)
+    }
+
     pub fn get_account_id(&self) -> near_sdk::AccountId {
         self.account_id.to_string().parse().unwrap()
     }
@@ -0,0 +1,61 @@
+mod contract_initializer;
+
+use crate::contract_initializer::Initializer;
+use maplit::hashmap;
+use near_units::parse_near;
+use scenario_toolset::{
+    context_initialize::initialize_context,
+    utils::{
+        access_key::{add_full_access_key, add_function_call_key, FunctionCallKey},
+        maker_id, TestAccount,
+    },
+};
+
+/// Example of the contract calls signed with the function-call access key
+/// Allowed methods should be executed, while the rest of calls should be rejected
+#[tokio::test]
+async fn test_function_call_key_signer() -> anyhow::Result<()> {
+    let (worker, contract_template, _, _, [maker_account]) = initialize_context(
+        &[],
+        &[TestAccount {
+            account_id: maker_id(),
+            mint_amount: hashmap! {},
+        }],
+        &Initializer {},
+    )
+    .await?;
+
+    let signer = add_function_call_key(
+        &worker,
+        &maker_account,
+        &FunctionCallKey::new(contract_template.contract.id().clone())
+            .methods(&["call_no_param_ret_u64"])
+            .allowance(parse_near!("1 N")),
+    )
+    .await?;
+
+    // Allowed method could be called with the function-call key
+    let res = contract_template.call_no_param_ret_u64(&signer).await?;
+    assert_eq!(res.value, 11);
+
+    // Method which is not in the allowed methods list should be rejected
+    let res = contract_template
+        .call_param_u64_ret_u64_handle_res(1, &signer)
+        .await;
+    assert!(res.is_err());
+
+    // Function-call keys are not allowed to attach the deposit
+    let res = contract_template
+        .call_no_param_no_ret_payable(&signer, parse_near!("1 yN"))
+        .await;
+    assert!(res.is_err());
+
+    // Full access key could call any method
+    let full_access_signer = add_full_access_key(&worker, &maker_account).await?;
+    let res = contract_template
+        .call_param_u64_ret_u64_handle_res(1, &full_access_signer)
+        .await?;
+    assert_eq!(res.value, 12);
+
+    Ok(())
+}
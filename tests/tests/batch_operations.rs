@@ -5,13 +5,19 @@ use crate::contract_initializer::Initializer;
 use futures::FutureExt;
 use integration_tests_toolset::{
     error::TestError,
+    pending_tx::block_reference::BlockReference,
     statistic::{
         call_counter::CallCounter,
+        error_rate::ErrorRate,
         gas_usage_aggregator::GasUsage,
+        group_by::GroupBy,
+        shared_consumer::SharedConsumer,
         statistic_consumer::{Statistic, StatisticConsumer},
         statistic_group_ext::StatisticGroupExt,
         statistic_group_printer::StatisticGroupPrinter,
+        statistic_printer::StatisticPrinter,
         storage_usage_aggregator::StorageUsage,
+        timeline::Timeline,
     },
     tx_result::{IntoMutRefs, TxResult},
 };
@@ -19,7 +25,7 @@ use maplit::hashmap;
 use operation_examples::{error_operation, numbered_operation, sleep_operation};
 use owo_colors::AnsiColors;
 use scenario_toolset::{
-    batch::{make_op, make_unit_op, Batch},
+    batch::{make_named_op, make_op, make_unit_op, Batch, ExecutionOperation},
     context_initialize::initialize_context,
     utils::{
         maker_id,
@@ -44,6 +50,116 @@ async fn test_minimal_batch() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Example of batch in the continue-on-error mode, failed operations are recorded into the statistic
+#[tokio::test]
+async fn test_continue_on_error() -> anyhow::Result<()> {
+    let (_, contract_template, _, _, _) = initialize_context(&[], &[], &Initializer {}).await?;
+
+    let stats = Batch::new()
+        .continue_on_error()
+        .add_concurrent_ops(vec![
+            make_op(contract_template.view_no_param_ret_error_handle_res()),
+            make_op(contract_template.view_no_param_ret_error_handle_res()),
+            make_op(contract_template.view_no_param_ret_u64()),
+            // the function name of the boxed future is recorded by the generated function
+            contract_template
+                .view_no_param_ret_error_handle_res()
+                .boxed()
+                .into(),
+        ])
+        .add_chain_op(
+            Batch::new()
+                .add_chain_op(make_named_op(
+                    "boxed_error_op",
+                    contract_template
+                        .view_no_param_ret_error_handle_res()
+                        .boxed(),
+                ))
+                .into(),
+        )
+        .run()
+        .await?;
+    assert_eq!(stats.iter().filter(|stat| stat.is_failed()).count(), 4);
+    // the failed calls keep the call info
+    let failed = stats.iter().find(|stat| stat.is_failed()).unwrap();
+    assert_eq!(
+        (failed.contract_id.as_ref(), failed.args.as_deref()),
+        (Some(contract_template.contract.id()), Some("{}"))
+    );
+
+    let mut error_rate = ErrorRate::default();
+    let mut timeline = Timeline::default();
+    stats.populate_statistic(&mut [&mut error_rate as &mut dyn StatisticConsumer, &mut timeline]);
+    // the failed operations are placed on the timeline as well
+    assert_eq!(timeline.entries.len(), 5);
+    assert_eq!(timeline.failures.len(), 4);
+
+    let outcomes = error_rate.func_outcomes["view_no_param_ret_error_handle_res"];
+    assert_eq!((outcomes.success, outcomes.failure), (0, 3));
+    assert_eq!(error_rate.func_outcomes["view_no_param_ret_u64"].success, 1);
+    assert_eq!(error_rate.func_outcomes["boxed_error_op"].failure, 1);
+    assert!(error_rate
+        .top_errors()
+        .iter()
+        .all(|((_, message), _)| message.contains("View function raised error!")));
+
+    error_rate.print_statistic()?;
+
+    Ok(())
+}
+
+/// Example of labeled operations, the statistic of the same function is grouped per label
+#[tokio::test]
+async fn test_labeled_operations() -> anyhow::Result<()> {
+    let (_, contract_template, _, _, [maker_account]) = initialize_context(
+        &[],
+        &[TestAccount {
+            account_id: maker_id(),
+            mint_amount: hashmap! {},
+        }],
+        &Initializer {},
+    )
+    .await?;
+
+    let stats = Batch::new()
+        .add_chain_op(
+            make_op(contract_template.call_no_param_no_ret_payable(&maker_account, 1))
+                .label("small"),
+        )
+        // the label of the sub batch is attached to all its operations
+        .add_chain_op(
+            ExecutionOperation::from(Batch::new().add_chain_ops(vec![
+                make_op(contract_template.call_no_param_no_ret_payable(&maker_account, 1_000)),
+                make_op(contract_template.call_no_param_no_ret_payable(&maker_account, 1_000)),
+            ]))
+            .label("large"),
+        )
+        .run()
+        .await?;
+
+    let mut call_counter = CallCounter::default().with_group_by(GroupBy::FunctionAndLabel);
+    let mut gas_usage = GasUsage::default().with_group_by(GroupBy::Label);
+    stats.populate_statistic(&mut [
+        &mut call_counter as &mut dyn StatisticConsumer,
+        &mut gas_usage,
+    ]);
+
+    assert_eq!(
+        call_counter.func_count["call_no_param_no_ret_payable [small]"],
+        1
+    );
+    assert_eq!(
+        call_counter.func_count["call_no_param_no_ret_payable [large]"],
+        2
+    );
+    assert_eq!(gas_usage.func_gas.statistics().len(), 2);
+
+    call_counter.print_statistic()?;
+    gas_usage.print_statistic()?;
+
+    Ok(())
+}
+
 /// Example of butch usage with custom operation that panics
 #[tokio::test]
 #[should_panic = "This operation always fails"]
@@ -159,16 +275,17 @@ async fn block_operations_example() -> anyhow::Result<()> {
     )
     .await?;
 
-    // Create statistic consumers separately to be able to use them in different futures
-    let mut statistic_consumer: [Box<dyn StatisticConsumer>; 1] = [Box::new(GasUsage::default())];
+    // Create shared statistic consumers to be able to use them in different futures
+    let mut statistic_consumer = [SharedConsumer::new(GasUsage::default())];
 
-    // This operation populate statistic to statistic_consumer itself
+    // This operation populate statistic to the clone of statistic_consumer itself
     // It can be used to inject statistic for custom operations
+    let mut gas_usage = statistic_consumer[0].clone();
     let future_that_populates_statistic_itself = contract_template
         .call_no_param_ret_u64(&maker_account)
-        .map(|res| {
+        .map(move |res| {
             res.map(|tx| {
-                tx.populate_statistic(&mut statistic_consumer.into_refs());
+                tx.populate_statistic(&mut [&mut gas_usage]);
                 42
             })
         });
@@ -229,3 +346,42 @@ async fn block_operations_example() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Example of batch with block tracking
+/// Recorded block heights are used to query the contract state as of every step of the batch
+#[tokio::test]
+async fn test_block_tracking() -> anyhow::Result<()> {
+    let (worker, contract_template, _, _, [maker_account]) = initialize_context(
+        &[],
+        &[TestAccount {
+            account_id: maker_id(),
+            mint_amount: hashmap! {},
+        }],
+        &Initializer {},
+    )
+    .await?;
+
+    let statistics = Batch::new()
+        .track_blocks(&worker)
+        .add_chain_ops(
+            (0..3)
+                .map(|_| make_op(contract_template.call_no_param_ret_u64(&maker_account)))
+                .collect::<Vec<_>>(),
+        )
+        .run()
+        .await?;
+
+    for (stat, expected_state) in statistics.iter().zip(11..) {
+        let block_height = stat
+            .reached_block_height
+            .expect("Block height should be recorded");
+        // the block which included the transaction is not measured
+        assert_eq!(stat.block_height, None);
+        let res = contract_template
+            .view_no_param_ret_u64_at(BlockReference::at_block(block_height))
+            .await?;
+        assert_eq!(res.value, expected_state);
+    }
+
+    Ok(())
+}
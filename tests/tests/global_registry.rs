@@ -0,0 +1,103 @@
+use integration_tests_toolset::statistic::{
+    call_counter::CallCounter, error_rate::ErrorRate, gas_usage_aggregator::GasUsage,
+    global_registry::GlobalRegistry, mode_printer::ModePrinter,
+};
+use test_contract::TestContractTest;
+
+// The registry is global for the process, so its test has the own binary: the calls of the other tests
+// are not recorded by it, and the registry installed by the failed test doesn't affect them
+
+// Disables the registry even if the test failed
+struct FinishOnDrop;
+
+impl Drop for FinishOnDrop {
+    fn drop(&mut self) {
+        let _ = GlobalRegistry::finish();
+    }
+}
+
+/// The generated calls populate the global registry, once it is installed
+#[tokio::test]
+async fn standalone_test_global_registry() -> anyhow::Result<()> {
+    let path = std::env::temp_dir().join(format!("summary_{}.txt", std::process::id()));
+    assert!(GlobalRegistry::new("global_registry")
+        .with_consumer(CallCounter::default())
+        .with_consumer(GasUsage::default())
+        .with_consumer(ErrorRate::default())
+        .with_mode_printer(ModePrinter::Mixed(path.clone()))
+        .install());
+    let _finish = FinishOnDrop;
+
+    let worker = workspaces::sandbox().await?;
+
+    let contract = worker
+        .dev_deploy(include_bytes!("../../res/test_contract.wasm"))
+        .await?;
+
+    let user = worker.dev_create_account().await?;
+
+    let contract_template = TestContractTest {
+        contract,
+        measure_storage_usage: false,
+    };
+
+    contract_template
+        .new(1, &contract_template.contract.as_account(), 1u128)
+        .await?;
+    contract_template.call_no_param_ret_u64(&user).await?;
+    contract_template.view_no_param_ret_u64().await?;
+    assert!(contract_template
+        .call_no_param_ret_error_handle_res(&user)
+        .await
+        .is_err());
+
+    let report = GlobalRegistry::combined_report().unwrap().render();
+    assert!(report.contains("call_no_param_ret_u64") && report.contains("view_no_param_ret_u64"));
+    // the failed calls are recorded too
+    assert!(report.contains("call_no_param_ret_error_handle_res"));
+
+    // the summary is printed on finish, so it is not printed again at the exit
+    GlobalRegistry::finish()?;
+    assert!(!GlobalRegistry::is_enabled());
+    let summary = std::fs::read_to_string(&path)?;
+    assert!(summary.contains("Run: global_registry") && summary.contains("Gas usage"));
+    std::fs::remove_file(&path)?;
+
+    Ok(())
+}
+
+// Installs the registry and exits without the explicit finish, it is run by `test_summary_at_exit` in the child process
+#[test]
+#[ignore]
+fn exit_hook_child() {
+    let Some(path) = std::env::var_os("SUMMARY_PATH") else {
+        return;
+    };
+    GlobalRegistry::new("exit_hook")
+        .with_consumer(CallCounter::default())
+        .with_mode_printer(ModePrinter::File(path.into()))
+        .install();
+}
+
+/// The summary is printed when the test process exits
+#[test]
+fn test_summary_at_exit() -> anyhow::Result<()> {
+    let path = std::env::temp_dir().join(format!("exit_summary_{}.txt", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let status = std::process::Command::new(std::env::current_exe()?)
+        .args([
+            "exit_hook_child",
+            "--exact",
+            "--ignored",
+            "--test-threads=1",
+        ])
+        .env("SUMMARY_PATH", &path)
+        .status()?;
+    assert!(status.success());
+
+    let summary = std::fs::read_to_string(&path)?;
+    assert!(summary.contains("Run: exit_hook"));
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
@@ -1,12 +1,25 @@
 use integration_tests_toolset::{
+    error::TestError,
+    pending_tx::{
+        call::Call, immutable_tx::ImmutablePendingTx, mutable_tx::MutablePendingTx,
+        payable_tx::PayablePendingTx, retry_policy::RetryPolicy, view::View,
+    },
     statistic::{
-        gas_usage_aggregator::GasUsage, statistic_consumer::StatisticConsumer,
-        statistic_group_printer::StatisticGroupPrinter, storage_usage_aggregator::StorageUsage,
+        failed_call::capture_failed_call,
+        gas_usage_aggregator::GasUsage,
+        mode_printer::ModePrinter,
+        numeric_aggregator::StatisticColumn,
+        statistic_consumer::{Statistic, StatisticConsumer},
+        statistic_group_printer::StatisticGroupPrinter,
+        statistic_printer::StatisticPrinter,
+        storage_usage_aggregator::StorageUsage,
     },
-    tx_result::IntoMutRefs,
+    tx_result::{IntoMutRefs, TxResultDetails},
 };
 use near_units::parse_near;
+use std::time::Duration;
 use test_contract::TestContractTest;
+use workspaces::error::ErrorKind;
 
 /// Test of bindgen macro generated functions
 /// This is mostly for internal testing of macro generated functions
@@ -160,8 +173,13 @@ async fn standalone_test_gen_functions() -> anyhow::Result<()> {
         .call_no_param_ret_error_handle_res(&user)
         .await;
 
-    assert!(res.is_err());
+    // the failure of the receipt is returned as is
     let res = res.unwrap_err();
+    assert!(
+        matches!(&res, TestError::Workspace(err) if matches!(err.kind(), ErrorKind::Execution)),
+        "Unexpected error: {:?}",
+        res
+    );
     println!("res: {}", res);
 
     statistic_consumers.print_statistic()?;
@@ -176,3 +194,347 @@ async fn standalone_test_gen_functions() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Test of the asynchronous transaction submission
+/// Both transactions are in flight at once, while their results are awaited in the controlled order
+#[tokio::test]
+async fn standalone_test_submit_functions() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+
+    let contract = worker
+        .dev_deploy(include_bytes!("../../res/test_contract.wasm"))
+        .await?;
+
+    let user = worker.dev_create_account().await?;
+
+    let contract_template = TestContractTest {
+        contract,
+        measure_storage_usage: false,
+    };
+
+    contract_template
+        .new(1, &contract_template.contract.as_account(), 1u128)
+        .await?;
+
+    let first = contract_template
+        .call_param_u64_ret_u64_handle_res_submit(2, &user)
+        .await?;
+    let second = contract_template
+        .call_no_param_no_ret_payable_submit(&user, parse_near!("1 yN"))
+        .await?;
+
+    // the second transaction is awaited first
+    second.wait().await?;
+    let res = first.wait().await?;
+    assert!(res.value == 3 || res.value == 4);
+
+    assert_eq!(contract_template.view_no_param_ret_u64().await?.value, 4);
+
+    // submitted failing transaction should return the error on wait
+    let failing = contract_template
+        .call_no_param_ret_error_handle_res_submit(&user)
+        .await?;
+    // the failure is recorded with the outcomes, as for the synchronous calls
+    let (res, failed_call) = capture_failed_call(failing.wait()).await;
+    assert!(res.is_err());
+    let failed_call = failed_call.unwrap();
+    assert_eq!(failed_call.func_name, "call_no_param_ret_error_handle_res");
+    assert_eq!(failed_call.caller.as_ref(), Some(user.id()));
+    assert!(matches!(failed_call.details, TxResultDetails::Call(_)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn standalone_test_retry_policy() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+
+    let contract = worker
+        .dev_deploy(include_bytes!("../../res/test_contract.wasm"))
+        .await?;
+
+    let contract_template = TestContractTest {
+        contract,
+        measure_storage_usage: false,
+    };
+
+    // the policy is set for the particular pending transaction, so other tests are not affected
+    let retry_policy = RetryPolicy {
+        max_retries: 5,
+        initial_backoff: Duration::from_millis(100),
+        timeout: Some(Duration::from_secs(30)),
+        ..Default::default()
+    };
+
+    let (res, retries) = PayablePendingTx::new(
+        &contract_template.contract,
+        String::from("new"),
+        br#"{"initial_state": 1}"#.to_vec(),
+        1,
+    )
+    .with_retry_policy(retry_policy.clone())
+    .call(contract_template.contract.as_account())
+    .await?;
+    assert!(res.is_success());
+    assert_eq!(retries, 0);
+
+    // contract failures are not retried
+    let (res, retries) = MutablePendingTx::new(
+        &contract_template.contract,
+        String::from("call_no_param_ret_error_handle_res"),
+        b"{}".to_vec(),
+    )
+    .with_retry_policy(retry_policy)
+    .call(contract_template.contract.as_account())
+    .await?;
+    assert!(res.is_failure());
+    assert_eq!(retries, 0);
+
+    // the policy could be also overridden for the particular pending transaction
+    let (res, retries) = ImmutablePendingTx::new(
+        &contract_template.contract,
+        String::from("view_no_param_ret_u64"),
+        b"{}".to_vec(),
+    )
+    .with_retry_policy(RetryPolicy::none())
+    .view()
+    .await?;
+    assert_eq!(res.json::<u64>()?, 1);
+    assert_eq!(retries, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn standalone_test_storage_deltas() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+
+    let contract = worker
+        .dev_deploy(include_bytes!("../../res/test_contract.wasm"))
+        .await?;
+
+    let user = worker.dev_create_account().await?;
+
+    let contract_template = TestContractTest {
+        contract,
+        measure_storage_usage: false,
+    };
+
+    let mut storage_usage: Box<dyn StatisticConsumer> = Box::new(StorageUsage::default());
+
+    // initialization of the state increases the contract storage
+    let res = contract_template
+        .new(1, &contract_template.contract.as_account(), 1u128)
+        .await?
+        .measure_storage_deltas(&worker)
+        .await?
+        .populate_statistic(&mut [&mut storage_usage]);
+    assert!(res.storage_deltas[contract_template.contract.id()] > 0);
+
+    // caller executes the refund receipt, so it is measured as well
+    let res = contract_template
+        .call_no_param_ret_u64(&user)
+        .await?
+        .measure_storage_deltas(&worker)
+        .await?
+        .populate_statistic(&mut [&mut storage_usage]);
+    assert_eq!(res.storage_deltas[contract_template.contract.id()], 0);
+    assert_eq!(res.storage_deltas[user.id()], 0);
+
+    // view results are not affected
+    let res = contract_template
+        .view_no_param_ret_u64()
+        .await?
+        .measure_storage_deltas(&worker)
+        .await?;
+    assert!(res.storage_deltas.is_empty());
+
+    storage_usage.print_statistic()?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn standalone_test_balance_deltas() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+
+    let contract = worker
+        .dev_deploy(include_bytes!("../../res/test_contract.wasm"))
+        .await?;
+
+    let user = worker.dev_create_account().await?;
+
+    let contract_template = TestContractTest {
+        contract,
+        measure_storage_usage: false,
+    };
+
+    contract_template
+        .new(1, &contract_template.contract.as_account(), 1u128)
+        .await?;
+
+    let deposit = parse_near!("1 yN");
+    let res = contract_template
+        .call_no_param_no_ret_payable(&user, deposit)
+        .await?
+        .measure_balance_deltas(&worker)
+        .await?
+        // the user pays the deposit and the gas
+        .assert_balance_delta_excluding_gas(user.id(), -(deposit as i128))?
+        // the contract receives the deposit and the part of burnt gas as the reward
+        .assert_balance_delta_excluding_gas(contract_template.contract.id(), deposit as i128)?;
+
+    assert_eq!(
+        res.balance_deltas[user.id()],
+        -(deposit as i128) - res.tokens_burnt() as i128
+    );
+
+    // the concurrent calls of the same user are not counted in the deltas of each other
+    let (first, second) = futures::try_join!(
+        contract_template.call_no_param_no_ret_payable(&user, deposit),
+        contract_template.call_no_param_no_ret_payable(&user, deposit),
+    )?;
+    for res in [first, second] {
+        let res = res.measure_balance_deltas(&worker).await?;
+        assert_eq!(
+            res.balance_deltas[user.id()],
+            -(deposit as i128) - res.tokens_burnt() as i128
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn standalone_test_call_info() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+
+    let contract = worker
+        .dev_deploy(include_bytes!("../../res/test_contract.wasm"))
+        .await?;
+
+    let user = worker.dev_create_account().await?;
+
+    let contract_template = TestContractTest {
+        contract,
+        measure_storage_usage: false,
+    };
+    let contract_id = contract_template.contract.id();
+
+    contract_template
+        .new(1, &contract_template.contract.as_account(), 1u128)
+        .await?;
+
+    let stat = Statistic::from(
+        contract_template
+            .call_no_param_no_ret_payable(&user, 1)
+            .await?
+            .measure_block_height(&worker)
+            .await?,
+    );
+    assert_eq!(stat.contract_id.as_ref(), Some(contract_id));
+    assert_eq!(stat.caller.as_ref(), Some(user.id()));
+    assert_eq!(stat.args.as_deref(), Some("{}"));
+    assert_eq!(stat.deposit, Some(1));
+    assert!(stat.tx_hash.is_some());
+
+    // the block which included the transaction
+    let block = worker
+        .view_block()
+        .block_height(stat.block_height.unwrap())
+        .await?;
+    assert_eq!(Some(block.hash()), stat.block_hash.as_ref());
+
+    // the caller and the transaction are unknown for the view calls
+    let stat = Statistic::from(contract_template.view_account_id(user.id().clone()).await?);
+    assert_eq!(
+        stat.args,
+        Some(format!("{{\"account\":\"{}\"}}", user.id()))
+    );
+    assert!(stat.caller.is_none() && stat.tx_hash.is_none() && stat.deposit.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn standalone_test_gas_cost() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+
+    let contract = worker
+        .dev_deploy(include_bytes!("../../res/test_contract.wasm"))
+        .await?;
+
+    let contract_template = TestContractTest {
+        contract,
+        measure_storage_usage: false,
+    };
+
+    // the first consumer reports the actual amount of burnt tokens,
+    // the second one estimates the cost with the fixed gas price
+    let gas_price = 100_000_000;
+    let mut burnt_cost = GasUsage::default();
+    let mut priced_cost = GasUsage::default().with_gas_price(gas_price);
+
+    let res = contract_template
+        .new(1, &contract_template.contract.as_account(), 1u128)
+        .await?
+        .populate_statistic(&mut [&mut burnt_cost, &mut priced_cost]);
+
+    let TxResultDetails::Call(call_result) = &res.details else {
+        panic!("call result is expected");
+    };
+    assert!(call_result.tokens_burnt() > 0);
+
+    let func_name = "new".to_owned();
+    let cost = |gas_usage: &GasUsage| gas_usage.func_cost.statistic(&func_name).unwrap().median;
+    assert_eq!(cost(&burnt_cost), call_result.tokens_burnt());
+    assert_eq!(cost(&priced_cost), call_result.gas as u128 * gas_price);
+
+    burnt_cost.print_statistic()?;
+    priced_cost.print_statistic()?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn standalone_test_statistic_columns() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+
+    let contract = worker
+        .dev_deploy(include_bytes!("../../res/test_contract.wasm"))
+        .await?;
+
+    let user = worker.dev_create_account().await?;
+
+    let contract_template = TestContractTest {
+        contract,
+        measure_storage_usage: true,
+    };
+
+    let columns = [
+        StatisticColumn::Mean,
+        StatisticColumn::StdDev,
+        StatisticColumn::P90,
+        StatisticColumn::P95,
+        StatisticColumn::P99,
+    ];
+    let mut statistic_consumers: [Box<dyn StatisticConsumer>; 2] = [
+        Box::new(GasUsage::new(ModePrinter::Console, &columns)),
+        Box::new(StorageUsage::new(ModePrinter::Console, &columns)),
+    ];
+
+    contract_template
+        .new(1, &contract_template.contract.as_account(), 1u128)
+        .await?;
+
+    for _ in 0..5 {
+        contract_template
+            .call_no_param_ret_u64(&user)
+            .await?
+            .populate_statistic(&mut statistic_consumers.into_refs());
+    }
+
+    statistic_consumers.print_statistic()?;
+
+    Ok(())
+}
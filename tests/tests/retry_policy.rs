@@ -0,0 +1,166 @@
+use integration_tests_toolset::{
+    error::TestError,
+    pending_tx::retry_policy::{failure_kind, FailureKind, RetryPolicy},
+};
+use std::time::{Duration, Instant};
+use workspaces::error::{Error, ErrorKind, RpcErrorCode};
+
+// Tests of the retry policy, which don't require the sandbox
+
+fn policy() -> RetryPolicy {
+    RetryPolicy {
+        max_retries: 3,
+        initial_backoff: Duration::from_millis(20),
+        backoff_multiplier: 2,
+        max_backoff: Duration::from_millis(50),
+        timeout: None,
+    }
+}
+
+fn rpc_error(code: RpcErrorCode) -> Error {
+    Error::from(ErrorKind::Rpc(code))
+}
+
+#[test]
+fn test_failure_kind() {
+    assert_eq!(
+        failure_kind(&rpc_error(RpcErrorCode::ConnectionFailure)),
+        FailureKind::NotSent
+    );
+    assert_eq!(
+        failure_kind(&rpc_error(RpcErrorCode::BroadcastTxFailure)),
+        FailureKind::Ambiguous
+    );
+    assert_eq!(
+        failure_kind(&Error::from(ErrorKind::Io)),
+        FailureKind::Ambiguous
+    );
+    assert_eq!(
+        failure_kind(&rpc_error(RpcErrorCode::QueryFailure)),
+        FailureKind::Fatal
+    );
+    assert_eq!(
+        failure_kind(&Error::from(ErrorKind::Execution)),
+        FailureKind::Fatal
+    );
+}
+
+#[test]
+fn test_execution_timeout() {
+    assert_eq!(
+        RetryPolicy {
+            timeout: Some(Duration::from_secs(5)),
+            ..policy()
+        }
+        .execution_timeout(),
+        Duration::from_secs(5)
+    );
+    // the wait for the execution is limited even without the timeout
+    assert_eq!(
+        RetryPolicy::none().execution_timeout(),
+        Duration::from_secs(60)
+    );
+}
+
+#[tokio::test]
+async fn test_retry_with_backoff() -> anyhow::Result<()> {
+    let policy = policy();
+    assert_eq!(
+        (1..=4)
+            .map(|retry| policy.backoff(retry))
+            .collect::<Vec<_>>(),
+        [20, 40, 50, 50].map(Duration::from_millis)
+    );
+
+    // the request fails twice before reaching the node and then succeeds
+    let mut attempts = 0;
+    let started_at = Instant::now();
+    let (res, retries) = policy
+        .execute(false, || {
+            attempts += 1;
+            let attempt = attempts;
+            async move {
+                match attempt {
+                    1 | 2 => Err(rpc_error(RpcErrorCode::ConnectionFailure)),
+                    _ => Ok(attempt),
+                }
+            }
+        })
+        .await?;
+    assert_eq!((res, retries), (3, 2));
+    assert!(started_at.elapsed() >= Duration::from_millis(60));
+
+    // the retries are limited
+    let mut attempts = 0;
+    let res = policy
+        .execute(false, || {
+            attempts += 1;
+            async { Err::<(), _>(rpc_error(RpcErrorCode::ConnectionFailure)) }
+        })
+        .await;
+    assert!(matches!(res, Err(TestError::Workspace(_))));
+    assert_eq!(attempts, 4);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ambiguous_failures() {
+    let policy = policy();
+
+    // the transaction could be already broadcast, so it is not sent again
+    let mut attempts = 0;
+    let res = policy
+        .execute(false, || {
+            attempts += 1;
+            async { Err::<(), _>(rpc_error(RpcErrorCode::BroadcastTxFailure)) }
+        })
+        .await;
+    assert!(res.is_err());
+    assert_eq!(attempts, 1);
+
+    // idempotent requests, e.g. views, are retried
+    let mut attempts = 0;
+    let res = policy
+        .execute(true, || {
+            attempts += 1;
+            let attempt = attempts;
+            async move {
+                match attempt {
+                    1 => Err(Error::from(ErrorKind::Io)),
+                    _ => Ok(attempt),
+                }
+            }
+        })
+        .await;
+    assert_eq!(res.ok(), Some((2, 1)));
+
+    // contract failures are never retried
+    let mut attempts = 0;
+    let res = policy
+        .execute(true, || {
+            attempts += 1;
+            async { Err::<(), _>(rpc_error(RpcErrorCode::QueryFailure)) }
+        })
+        .await;
+    assert!(res.is_err());
+    assert_eq!(attempts, 1);
+
+    // timed out broadcast is ambiguous as well
+    let policy = RetryPolicy {
+        timeout: Some(Duration::from_millis(10)),
+        ..policy
+    };
+    let mut attempts = 0;
+    let res = policy
+        .execute(false, || {
+            attempts += 1;
+            async {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                Ok(())
+            }
+        })
+        .await;
+    assert!(matches!(res, Err(TestError::Timeout(_))));
+    assert_eq!(attempts, 1);
+}
@@ -0,0 +1,133 @@
+mod statistic_fixtures;
+
+use crate::statistic_fixtures::{outcome, storage_stat};
+use integration_tests_toolset::{
+    error::TestError,
+    statistic::{
+        baseline::BaselineComparator,
+        gas_usage_aggregator::GasUsage,
+        report::{strip_ansi, ReportFormat},
+        snapshot::Snapshot,
+        statistic_consumer::{Statistic, StatisticConsumer},
+        storage_usage_aggregator::StorageUsage,
+    },
+    tx_result::{CallResult, TxResultDetails},
+};
+
+// Tests of the baseline comparison and report snapshots, which don't require the sandbox
+
+#[test]
+fn test_baseline_comparison() -> anyhow::Result<()> {
+    let path = std::env::temp_dir().join(format!("baseline_{}.json", std::process::id()));
+
+    let mut storage_usage = StorageUsage::default();
+    storage_usage.consume_statistic(&storage_stat("call_no_param_ret_u64", 100));
+    storage_usage.save_baseline(&path)?;
+
+    // gas section is saved into the same file
+    GasUsage::default().save_baseline(&path)?;
+    let baseline = std::fs::read_to_string(&path)?;
+    assert!(baseline.contains("\"gas\"") && baseline.contains("\"storage\""));
+
+    storage_usage.consume_statistic(&storage_stat("call_no_param_ret_u64", 104));
+    storage_usage.compare_with_baseline(&path, 5.)?;
+
+    storage_usage.clean_statistic();
+    storage_usage.consume_statistic(&storage_stat("call_no_param_ret_u64", 110));
+    assert!(matches!(
+        storage_usage.compare_with_baseline(&path, 5.),
+        Err(TestError::BaselineExceeded(_))
+    ));
+
+    // functions of the baseline which are not called in the current run are reported
+    storage_usage.clean_statistic();
+    storage_usage.consume_statistic(&storage_stat("call_with_param", 100));
+    let comparison = storage_usage.baseline_comparison(&path, 5.)?;
+    assert_eq!(comparison.missing, vec!["call_no_param_ret_u64".to_owned()]);
+    assert!(comparison.exceeded.is_empty());
+    let report = strip_ansi(&comparison.make_report(ReportFormat::Table));
+    assert!(report.contains("missing") && report.contains("new"));
+
+    std::fs::remove_file(path)?;
+    Ok(())
+}
+
+#[test]
+fn test_report_snapshot() -> anyhow::Result<()> {
+    let path = std::env::temp_dir().join(format!("snapshot_{}.json", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    // the update mode of the environment is overridden, so the mismatch is not overwritten
+    let snapshot = Snapshot::new(&path)
+        .with_precision(2)
+        .with_tolerance(5.)
+        .with_update_mode(false);
+
+    // missing snapshot is an error unless it is written in the update mode
+    let mut storage_usage = StorageUsage::default();
+    storage_usage.consume_statistic(&storage_stat("call_no_param_ret_u64", 1_234));
+    match snapshot.assert_matches(&storage_usage) {
+        Err(TestError::SnapshotMismatch(message)) => assert!(message.contains("doesn't exist")),
+        result => panic!("Unexpected result: {:?}", result),
+    }
+    assert!(!path.exists());
+    snapshot
+        .clone()
+        .with_update_mode(true)
+        .assert_matches(&storage_usage)?;
+    let content = std::fs::read_to_string(&path)?;
+    assert!(content.contains("call_no_param_ret_u64") && content.contains("1200"));
+
+    storage_usage.clean_statistic();
+    storage_usage.consume_statistic(&storage_stat("call_no_param_ret_u64", 1_240));
+    snapshot.assert_matches(&storage_usage)?;
+
+    storage_usage.clean_statistic();
+    storage_usage.consume_statistic(&storage_stat("call_no_param_ret_u64", 1_400));
+    storage_usage.consume_statistic(&storage_stat("call_with_param", 100));
+    match snapshot.assert_matches(&storage_usage) {
+        Err(TestError::SnapshotMismatch(message)) => {
+            assert!(message.contains("call_no_param_ret_u64"));
+            assert!(message.contains("call_with_param: added"));
+        }
+        result => panic!("Unexpected result: {:?}", result),
+    }
+
+    // gas cells are the objects with the gas and its cost in NEAR, both are rounded
+    let user_id = "user.test.near".parse().unwrap();
+    let call = |gas| Statistic {
+        func_name: "call_no_param_ret_u64".to_owned(),
+        details: TxResultDetails::Call(CallResult {
+            gas,
+            transaction_outcome: outcome(&user_id, gas),
+            receipt_failures: vec![],
+            receipt_outcomes: vec![],
+        }),
+        ..Default::default()
+    };
+    let mut gas_usage = GasUsage::default();
+    gas_usage.consume_statistic(&call(2_345_678_901_234));
+    snapshot
+        .clone()
+        .with_update_mode(true)
+        .assert_matches(&gas_usage)?;
+    let content = std::fs::read_to_string(&path)?;
+    assert!(content.contains("\"gas\": 2300000000000"));
+    assert!(content.contains("\"near\": 0.00023"));
+
+    gas_usage.clean_statistic();
+    gas_usage.consume_statistic(&call(2_390_000_000_000));
+    snapshot.assert_matches(&gas_usage)?;
+
+    gas_usage.clean_statistic();
+    gas_usage.consume_statistic(&call(2_600_000_000_000));
+    match snapshot.assert_matches(&gas_usage) {
+        Err(TestError::SnapshotMismatch(message)) => {
+            assert!(message.contains("/Median/gas: 2300000000000 -> 2600000000000"));
+            assert!(message.contains("/Median/near: 0.00023 -> 0.00026"));
+        }
+        result => panic!("Unexpected result: {:?}", result),
+    }
+
+    std::fs::remove_file(path)?;
+    Ok(())
+}
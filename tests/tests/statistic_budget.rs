@@ -0,0 +1,114 @@
+mod statistic_fixtures;
+
+use crate::statistic_fixtures::{outcome, storage_stat};
+use integration_tests_toolset::{
+    error::TestError,
+    statistic::{
+        budget::{Budget, BudgetLimit, BudgetViolation, MethodBudget},
+        statistic_consumer::{Statistic, StatisticConsumer},
+    },
+    tx_result::{CallResult, TxResultDetails},
+};
+use workspaces::AccountId;
+
+// Tests of the budget limits, which don't require the sandbox
+
+#[test]
+fn test_budget_limits() {
+    let mut budget = Budget::default()
+        .with_method_budget(
+            "call_no_param_ret_u64",
+            MethodBudget::default().with_max_storage(100),
+        )
+        .with_default_budget(MethodBudget::default().with_max_storage(10));
+
+    budget.consume_statistic(&storage_stat("call_no_param_ret_u64", 100));
+    budget.consume_statistic(&storage_stat("call_with_param_ret_u64", 5));
+    assert!(budget.assert_within_limits().is_ok());
+
+    budget.consume_statistic(&storage_stat("call_no_param_ret_u64", 120));
+    budget.consume_statistic(&storage_stat("call_with_param_ret_u64", 11));
+    assert_eq!(budget.violations.len(), 2);
+    assert_eq!(
+        budget.violations[1].limit,
+        BudgetLimit::Storage {
+            limit: 10,
+            actual: 11
+        }
+    );
+    match budget.assert_within_limits() {
+        Err(TestError::BudgetExceeded(message)) => assert_eq!(
+            message,
+            "2 call(s) exceeded the budget:\n\
+             call_no_param_ret_u64: storage 120 bytes > 100 bytes\n\
+             call_with_param_ret_u64: storage 11 bytes > 10 bytes"
+        ),
+        result => panic!("Unexpected result: {:?}", result),
+    }
+
+    budget.clean_statistic();
+    assert!(budget.assert_within_limits().is_ok());
+}
+
+#[test]
+fn test_budget_gas_and_receipts() {
+    let contract_id: AccountId = "contract.test.near".parse().unwrap();
+    let outcome = outcome(&contract_id, 2_500_000_000_000);
+    let call = |receipts| Statistic {
+        func_name: "ft_transfer_call".to_owned(),
+        details: TxResultDetails::Call(CallResult {
+            gas: 7_500_000_000_000,
+            transaction_outcome: outcome.clone(),
+            receipt_failures: vec![],
+            receipt_outcomes: vec![outcome.clone(); receipts],
+        }),
+        ..Default::default()
+    };
+
+    let mut budget = Budget::default().with_method_budget(
+        "ft_transfer_call",
+        MethodBudget::default()
+            .with_max_gas(10_000_000_000_000)
+            .with_max_receipts(2),
+    );
+    budget.consume_statistic(&call(2));
+    assert!(budget.assert_within_limits().is_ok());
+
+    budget.consume_statistic(&call(3));
+    assert_eq!(
+        budget.violations[0].limit,
+        BudgetLimit::Receipts {
+            limit: 2,
+            actual: 3
+        }
+    );
+
+    let mut budget = Budget::default().with_method_budget(
+        "ft_transfer_call",
+        MethodBudget::default().with_max_gas(5_000_000_000_000),
+    );
+    budget.consume_statistic(&call(1));
+    assert_eq!(
+        budget.violations,
+        vec![BudgetViolation {
+            func_name: "ft_transfer_call".to_owned(),
+            limit: BudgetLimit::Gas {
+                limit: 5_000_000_000_000,
+                actual: 7_500_000_000_000
+            }
+        }]
+    );
+    assert!(matches!(
+        budget.assert_within_limits(),
+        Err(TestError::BudgetExceeded(message))
+            if message.ends_with("ft_transfer_call: gas 7.500 Tgas > 5.000 Tgas")
+    ));
+
+    // the failed call which burns over the budget is a violation too
+    budget.clean_statistic();
+    budget.consume_statistic(&Statistic {
+        error: Some("Smart contract panicked".to_owned()),
+        ..call(1)
+    });
+    assert_eq!(budget.violations.len(), 1);
+}
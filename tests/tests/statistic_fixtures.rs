@@ -0,0 +1,33 @@
+#![allow(dead_code)]
+
+use integration_tests_toolset::{
+    statistic::statistic_consumer::Statistic,
+    tx_result::{OutcomeDetails, OutcomeStatus},
+};
+use workspaces::{types::CryptoHash, AccountId};
+
+// Fixtures shared by the statistic tests, not every test binary uses all of them
+
+/// Successful call of the function with the measured storage usage
+pub fn storage_stat(func_name: &str, storage_usage: i64) -> Statistic {
+    Statistic {
+        func_name: func_name.to_owned(),
+        storage_usage: Some(storage_usage),
+        ..Default::default()
+    }
+}
+
+/// Successful outcome without the logs and receipts, the burnt tokens follow the gas price of the sandbox
+pub fn outcome(executor_id: &AccountId, gas_burnt: u64) -> OutcomeDetails {
+    OutcomeDetails {
+        transaction_hash: CryptoHash::default(),
+        block_hash: CryptoHash::default(),
+        logs: vec![],
+        receipt_ids: vec![],
+        gas_burnt,
+        tokens_burnt: gas_burnt as u128 * 100_000_000,
+        executor_id: executor_id.clone(),
+        status: OutcomeStatus::Success,
+        method_name: None,
+    }
+}
@@ -0,0 +1,102 @@
+mod statistic_fixtures;
+
+use crate::statistic_fixtures::outcome;
+use integration_tests_toolset::{
+    error::TestError,
+    statistic::{
+        combined_report::CombinedReport,
+        gas_usage_aggregator::GasUsage,
+        report::ReportFormat,
+        statistic_consumer::{Statistic, StatisticConsumer},
+        statistic_group_ext::StatisticGroupExt,
+        statistic_printer::StatisticPrinter,
+        timeline::Timeline,
+    },
+    tx_result::{CallResult, OutcomeDetails, OutcomeStatus, TxResultDetails},
+};
+use workspaces::{types::CryptoHash, AccountId};
+
+// Tests of the receipt tree, timeline and HTML report, which don't require the sandbox
+
+#[test]
+fn test_html_report() -> anyhow::Result<()> {
+    let contract_id: AccountId = "contract.test.near".parse().unwrap();
+    let token_id: AccountId = "token.test.near".parse().unwrap();
+    let outcome = |id: u8, executor_id: &AccountId, receipt_ids: &[u8]| OutcomeDetails {
+        transaction_hash: CryptoHash([id; 32]),
+        receipt_ids: receipt_ids.iter().map(|id| CryptoHash([*id; 32])).collect(),
+        ..outcome(executor_id, 2_500_000_000_000)
+    };
+    let failed_receipt = OutcomeDetails {
+        status: OutcomeStatus::Failure("Smart contract panicked: <no tokens>".to_owned()),
+        ..outcome(2, &token_id, &[])
+    };
+    // the tree is restored by the receipt ids regardless of the order of the outcomes
+    let call_result = CallResult {
+        gas: 7_500_000_000_000,
+        transaction_outcome: outcome(0, &contract_id, &[1]),
+        receipt_failures: vec![failed_receipt.clone()],
+        receipt_outcomes: vec![failed_receipt, outcome(1, &contract_id, &[2])],
+    };
+    assert_eq!(call_result.receipt_depth(), 2);
+    assert_eq!(
+        call_result.receipt_tree(),
+        "contract.test.near 2.500 Tgas\n\
+         └ contract.test.near 2.500 Tgas\n  \
+         └ token.test.near 2.500 Tgas [failed]"
+    );
+
+    let started_at = std::time::SystemTime::now();
+    let timed = |stat: Statistic, start, finish| Statistic {
+        started_at: Some(started_at + std::time::Duration::from_millis(start)),
+        finished_at: Some(started_at + std::time::Duration::from_millis(finish)),
+        ..stat
+    };
+    let error = TestError::Custom("Smart contract panicked: <no tokens>".to_owned());
+    let stats = vec![
+        timed(
+            Statistic::failed("ft_transfer_call", &error)
+                .with_details(TxResultDetails::Call(call_result)),
+            10,
+            30,
+        ),
+        timed(
+            Statistic {
+                func_name: "call_no_param_ret_u64".to_owned(),
+                ..Default::default()
+            },
+            0,
+            20,
+        ),
+    ];
+
+    let mut timeline = Timeline::default();
+    let mut gas_usage = GasUsage::default();
+    stats.populate_statistic(&mut [&mut timeline as &mut dyn StatisticConsumer, &mut gas_usage]);
+    // the failed calls are not aggregated by the gas usage
+    assert!(gas_usage.func_gas.statistics().is_empty());
+
+    let tables = timeline.report_tables();
+    let rows = &tables[0].rows;
+    assert_eq!(rows[0][1].value, "call_no_param_ret_u64");
+    assert_eq!(
+        (rows[1][2].value.as_f64(), rows[1][4].value.as_str()),
+        (Some(10.), Some("failed"))
+    );
+    assert_eq!(rows[0][5].value, "█".repeat(26));
+    assert_eq!(
+        rows[1][5].value,
+        format!("{}{}", " ".repeat(13), "█".repeat(26))
+    );
+    assert_eq!(tables[1].rows[0][1].value, "<no tokens>");
+
+    let mut report = CombinedReport::new("scenario").with_report_format(ReportFormat::Html);
+    report.add_consumer(&timeline);
+    let html = report.render();
+    assert!(html.starts_with("<!DOCTYPE html>") && html.ends_with("</html>\n"));
+    assert!(html.contains("<h2>Timeline</h2>") && html.contains("<h3>Failures</h3>"));
+    assert!(html.contains("<td>&lt;no tokens&gt;</td>"));
+    assert!(html.contains("└ token.test.near 2.500 Tgas [failed]"));
+
+    Ok(())
+}
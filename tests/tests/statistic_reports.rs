@@ -0,0 +1,439 @@
+mod statistic_fixtures;
+
+use crate::statistic_fixtures::{outcome, storage_stat};
+use integration_tests_toolset::{
+    error::TestError,
+    statistic::{
+        call_counter::CallCounter,
+        combined_report::CombinedReport,
+        error_rate::{panic_message, ErrorRate},
+        failed_call::{capture_failed_call, record_failed_call},
+        gas_breakdown::GasBreakdown,
+        global_registry::GlobalRegistry,
+        group_by::{GroupBy, UNLABELED},
+        latency_usage::LatencyUsage,
+        mode_printer::ModePrinter,
+        numeric_aggregator::{NumericAggregator, StatisticColumn},
+        report::{strip_ansi, ReportFormat, ReportTable},
+        shared_consumer::SharedConsumer,
+        statistic_consumer::{Statistic, StatisticConsumer},
+        statistic_group_ext::StatisticGroupExt,
+        statistic_group_printer::StatisticGroupPrinter,
+        statistic_printer::StatisticPrinter,
+        storage_usage_aggregator::StorageUsage,
+    },
+    tx_result::{CallResult, OutcomeDetails, TxResultDetails},
+};
+use owo_colors::OwoColorize;
+use workspaces::{types::CryptoHash, AccountId};
+
+// Tests of the statistic aggregation and reports, which don't require the sandbox.
+// Baselines and snapshots, budgets, traces and the HTML report are tested in the own `statistic_*.rs` files
+
+#[test]
+fn test_numeric_aggregator() {
+    let mut aggregator = NumericAggregator::default();
+    (1..=100u64).for_each(|value| aggregator.push("func", value));
+
+    let statistic = aggregator.statistic(&"func").unwrap();
+    assert_eq!(statistic.count, 100);
+    assert_eq!((statistic.min, statistic.max), (1, 100));
+    assert_eq!(statistic.median, 50);
+    assert_eq!(statistic.mean, 50.5);
+    assert_eq!((statistic.p90, statistic.p95, statistic.p99), (90, 95, 99));
+    assert_eq!(statistic.value(StatisticColumn::StdDev), 29);
+
+    // keys with the same median are sorted by the key
+    let mut aggregator = NumericAggregator::default();
+    for key in ["c", "a", "d", "b"] {
+        aggregator.push(key, if key == "d" { 2u64 } else { 1 });
+    }
+    let keys: Vec<_> = aggregator
+        .statistics()
+        .into_iter()
+        .map(|(key, _)| *key)
+        .collect();
+    assert_eq!(keys, vec!["d", "a", "b", "c"]);
+}
+
+#[test]
+fn test_report_formats() -> anyhow::Result<()> {
+    let stat = storage_stat("call_no_param_ret_u64", 100);
+
+    let mut call_counter = CallCounter::default().with_report_format(ReportFormat::Json);
+    call_counter.consume_statistic(&stat);
+    call_counter.consume_statistic(&stat);
+    let report: serde_json::Value = serde_json::from_str(&call_counter.make_report())?;
+    assert_eq!(report[0]["title"], "Number of calls");
+    assert_eq!(report[0]["rows"][0]["Count"], 2);
+
+    let mut storage_usage = StorageUsage::default().with_report_format(ReportFormat::Csv);
+    storage_usage.consume_statistic(&stat);
+    let report = storage_usage.make_report();
+    assert!(report
+        .starts_with("# Storage usage\nFunction,Count,Min,Median,Max\ncall_no_param_ret_u64,1,"));
+    assert_eq!(report, strip_ansi(&report));
+
+    let storage_usage = StorageUsage::default().with_report_format(ReportFormat::Markdown);
+    assert!(storage_usage
+        .make_report()
+        .starts_with("### Storage usage\n\n| Function | Count | Min | Median | Max |\n| --- |"));
+
+    Ok(())
+}
+
+// Printer which builds the text report itself, as before the report tables were introduced
+struct TextPrinter {
+    mode_printer: ModePrinter,
+}
+
+impl StatisticPrinter for TextPrinter {
+    fn get_printer_mode(&self) -> &ModePrinter {
+        &self.mode_printer
+    }
+
+    fn report_tables(&self) -> Vec<ReportTable> {
+        vec![ReportTable::text(
+            &self.report_name(),
+            &"Custom report".green().to_string(),
+        )]
+    }
+}
+
+#[test]
+fn test_text_printer() -> anyhow::Result<()> {
+    let printer = TextPrinter {
+        mode_printer: ModePrinter::default(),
+    };
+    assert_eq!(printer.get_report_format(), ReportFormat::Table);
+
+    let tables = printer.report_tables();
+    assert_eq!(tables.len(), 1);
+    assert_eq!(tables[0].title, "TextPrinter");
+    assert_eq!(tables[0].rows[0][0].value, "Custom report");
+    assert!(strip_ansi(&printer.make_report()).contains("Custom report"));
+
+    // the text report is a section of the combined report
+    let mut report = CombinedReport::new("Run").with_report_format(ReportFormat::Json);
+    report.add_consumer(&printer);
+    let report: serde_json::Value = serde_json::from_str(&report.render())?;
+    assert!(report.to_string().contains("Custom report"));
+
+    Ok(())
+}
+
+#[test]
+fn test_combined_report() -> anyhow::Result<()> {
+    let path = std::env::temp_dir().join(format!("combined_{}.txt", std::process::id()));
+    let stat = storage_stat("call_no_param_ret_u64", 100);
+
+    // consumers sharing the same file append their reports
+    let mut call_counter = CallCounter::new(ModePrinter::AppendFile(path.clone()));
+    let mut storage_usage = StorageUsage::new(ModePrinter::AppendFile(path.clone()), &[]);
+    call_counter.consume_statistic(&stat);
+    storage_usage.consume_statistic(&stat);
+    call_counter.print_statistic()?;
+    storage_usage.print_statistic()?;
+    let report = std::fs::read_to_string(&path)?;
+    assert!(report.contains("Number of calls") && report.contains("Storage usage"));
+
+    let consumers: [Box<dyn StatisticConsumer>; 2] =
+        [Box::new(call_counter), Box::new(storage_usage)];
+    consumers.combined_report("scenario").write(&path)?;
+    let report = std::fs::read_to_string(&path)?;
+    assert!(report.starts_with("===== Run: scenario ("));
+    assert!(report.contains("----- CallCounter -----\nNumber of calls"));
+    assert!(report.contains("----- StorageUsage -----\nStorage usage"));
+
+    let report = CombinedReport::new("scenario").with_report_format(ReportFormat::Markdown);
+    report.append(&path)?;
+    report.append(&path)?;
+    let content = std::fs::read_to_string(&path)?;
+    assert_eq!(content.matches("# scenario (").count(), 2);
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+fn test_shared_consumer() {
+    let stat = Statistic {
+        func_name: "call_no_param_ret_u64".to_owned(),
+        ..Default::default()
+    };
+
+    let call_counter = SharedConsumer::new(CallCounter::default());
+    // every clone populates the same consumer, even from the other threads
+    std::thread::scope(|scope| {
+        for _ in 0..4 {
+            let (call_counter, stat) = (call_counter.clone(), &stat);
+            scope.spawn(move || call_counter.consume(stat));
+        }
+    });
+
+    let mut other = call_counter.clone();
+    vec![stat].populate_statistic(&mut [&mut other]);
+    assert_eq!(call_counter.report_name(), "CallCounter");
+    let rows = &call_counter.report_tables()[0].rows;
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0][0].value, "call_no_param_ret_u64");
+    assert_eq!(rows[0][1].value, 5);
+
+    // the cleaning through any clone affects all of them
+    other.clean_statistic();
+    assert!(call_counter.report_tables()[0].rows.is_empty());
+}
+
+#[test]
+fn test_latency_usage() -> anyhow::Result<()> {
+    let started_at = std::time::SystemTime::now();
+    let stat = |millis| Statistic {
+        func_name: "call_no_param_ret_u64".to_owned(),
+        started_at: Some(started_at),
+        finished_at: Some(started_at + std::time::Duration::from_millis(millis)),
+        ..Default::default()
+    };
+
+    let mut latency_usage = LatencyUsage::default().with_report_format(ReportFormat::Json);
+    (1..=20).for_each(|millis| latency_usage.consume_statistic(&stat(millis)));
+    // calls without timestamps are skipped
+    latency_usage.consume_statistic(&Statistic::default());
+
+    let report: serde_json::Value = serde_json::from_str(&latency_usage.make_report())?;
+    let row = &report[0]["rows"][0];
+    assert_eq!(row["Count"], 20);
+    assert_eq!(
+        (row["Min"].as_f64(), row["Max"].as_f64()),
+        (Some(1.), Some(20.))
+    );
+    assert_eq!(
+        (row["Median"].as_f64(), row["P95"].as_f64()),
+        (Some(10.5), Some(19.))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_error_rate() {
+    let error = "Action #0: ExecutionError(\"Smart contract panicked: Not enough balance\")";
+    assert_eq!(panic_message(error), "Not enough balance");
+    assert_eq!(panic_message("Timeout"), "Timeout");
+
+    let mut error_rate = ErrorRate::default().with_top_errors(1);
+    error_rate.consume_statistic(&Statistic {
+        func_name: "call_no_param_ret_u64".to_owned(),
+        ..Default::default()
+    });
+    for _ in 0..3 {
+        error_rate.consume_statistic(&Statistic::failed(
+            "call_no_param_ret_u64",
+            &TestError::Custom(error.to_owned()),
+        ));
+    }
+    error_rate.consume_statistic(&Statistic::failed(
+        "call_no_param_ret_u64",
+        &TestError::Custom("Other error".to_owned()),
+    ));
+
+    let outcomes = error_rate.func_outcomes["call_no_param_ret_u64"];
+    assert_eq!(outcomes.error_rate(), 80.);
+    let top_errors = error_rate.top_errors();
+    assert_eq!(top_errors.len(), 1);
+    assert_eq!(top_errors[0].0 .1, "Not enough balance");
+    assert_eq!(top_errors[0].1, 3);
+}
+
+#[test]
+fn test_gas_breakdown_rows() {
+    let maker_id: AccountId = "maker.test.near".parse().unwrap();
+    let token_id: AccountId = "token.test.near".parse().unwrap();
+    let receiver_id: AccountId = "receiver.test.near".parse().unwrap();
+    let outcome = |id: u8, executor_id: &AccountId, method: Option<&str>, receipt_ids: &[u8]| {
+        OutcomeDetails {
+            transaction_hash: CryptoHash([id; 32]),
+            receipt_ids: receipt_ids.iter().map(|id| CryptoHash([*id; 32])).collect(),
+            method_name: method.map(str::to_owned),
+            ..outcome(
+                executor_id,
+                1_000_000_000_000 * id as u64 + 1_000_000_000_000,
+            )
+        }
+    };
+    let call = Statistic {
+        func_name: "ft_transfer_call".to_owned(),
+        details: TxResultDetails::Call(CallResult {
+            gas: 15_000_000_000_000,
+            transaction_outcome: outcome(0, &maker_id, Some("ft_transfer_call"), &[1]),
+            receipt_failures: vec![],
+            receipt_outcomes: vec![
+                outcome(1, &token_id, Some("ft_transfer_call"), &[2, 3]),
+                outcome(2, &receiver_id, Some("ft_on_transfer"), &[]),
+                outcome(3, &token_id, Some("ft_resolve_transfer"), &[4]),
+                outcome(4, &maker_id, None, &[]),
+            ],
+        }),
+        ..Default::default()
+    };
+
+    let mut gas_breakdown = GasBreakdown::default();
+    gas_breakdown.consume_statistic(&call);
+
+    // rows are ordered by the function, depth, executor and method
+    let tables = gas_breakdown.report_tables();
+    let rows: Vec<_> = tables[0]
+        .rows
+        .iter()
+        .map(|row| {
+            (
+                row[1].value.as_u64().unwrap(),
+                row[2].value.as_str().unwrap().to_owned(),
+                row[3].value.as_str().unwrap().to_owned(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        rows,
+        [
+            (0, "maker.test.near", "ft_transfer_call"),
+            (1, "token.test.near", "ft_transfer_call"),
+            (2, "receiver.test.near", "ft_on_transfer"),
+            (2, "token.test.near", "ft_resolve_transfer"),
+            (3, "maker.test.near", "-"),
+        ]
+        .map(|(depth, executor, method)| (depth, executor.to_owned(), method.to_owned()))
+    );
+
+    let key = (
+        "ft_transfer_call".to_owned(),
+        2,
+        receiver_id.clone(),
+        Some("ft_on_transfer".to_owned()),
+    );
+    assert_eq!(
+        gas_breakdown.receipt_gas.statistic(&key).unwrap().max,
+        3_000_000_000_000
+    );
+    assert_eq!(
+        gas_breakdown.contract_share("ft_transfer_call", &token_id),
+        (2. + 4.) / 15. * 100.
+    );
+}
+
+#[tokio::test]
+async fn test_failed_call_capture() {
+    let call = |func_name: &'static str, fails: bool| async move {
+        tokio::task::yield_now().await;
+        if !fails {
+            return Ok(func_name);
+        }
+        let err = TestError::Custom(format!("{} failed", func_name));
+        record_failed_call(Statistic::failed(func_name, &err));
+        Err(err)
+    };
+
+    // the failed call recorded outside of the capture is ignored
+    assert!(call("view_account_id", true).await.is_err());
+
+    // the concurrent operations capture their own calls
+    let ((first, first_call), (second, second_call), (third, third_call)) = futures::join!(
+        capture_failed_call(call("call_no_param_ret_u64", true)),
+        capture_failed_call(call("view_no_param_ret_u64", true)),
+        capture_failed_call(call("view_account_id", false)),
+    );
+    assert!(first.is_err() && second.is_err());
+    assert_eq!(first_call.unwrap().func_name, "call_no_param_ret_u64");
+    assert_eq!(
+        second_call.unwrap().error.as_deref(),
+        Some("Test error: view_no_param_ret_u64 failed")
+    );
+    assert_eq!((third.ok(), third_call), (Some("view_account_id"), None));
+}
+
+#[test]
+fn test_group_by_label() {
+    let stats = vec![
+        storage_stat("ft_transfer", 100).with_label("small"),
+        storage_stat("ft_transfer", 200).with_label("large"),
+        storage_stat("ft_transfer", 300).with_label("large"),
+        storage_stat("storage_deposit", 400),
+    ];
+
+    let mut by_function = CallCounter::default();
+    let mut by_label = CallCounter::default().with_group_by(GroupBy::Label);
+    let mut by_both = StorageUsage::default().with_group_by(GroupBy::FunctionAndLabel);
+    stats.populate_statistic(&mut [
+        &mut by_function as &mut dyn StatisticConsumer,
+        &mut by_label,
+        &mut by_both,
+    ]);
+
+    assert_eq!(by_function.func_count["ft_transfer"], 3);
+    assert_eq!(by_label.func_count["large"], 2);
+    assert_eq!(by_label.func_count[UNLABELED], 1);
+    assert_eq!(by_label.report_tables()[0].header[0], "Label");
+
+    let mut statistics = by_both.func_storage.statistics();
+    statistics.sort_by(|a, b| a.0.cmp(b.0));
+    let keys: Vec<_> = statistics.iter().map(|(key, _)| key.as_str()).collect();
+    assert_eq!(
+        keys,
+        [
+            "ft_transfer [large]",
+            "ft_transfer [small]",
+            "storage_deposit"
+        ]
+    );
+    assert_eq!(statistics[0].1.max, 300);
+    assert_eq!(
+        GroupBy::Label.key(
+            &storage_stat("ft_transfer", 0)
+                .with_label("a")
+                .with_label("b")
+        ),
+        "a, b"
+    );
+}
+
+#[test]
+fn test_global_registry() -> anyhow::Result<()> {
+    let path = std::env::temp_dir().join(format!("registry_{}.md", std::process::id()));
+    // the name is not used by other tests, since the registry records the calls of the concurrent tests too
+    let stat = Statistic {
+        func_name: "registry_call".to_owned(),
+        ..Default::default()
+    };
+
+    // the statistic is ignored until the registry is installed
+    GlobalRegistry::record(&stat);
+    assert!(GlobalRegistry::combined_report().is_none());
+
+    let registry = || {
+        GlobalRegistry::new("suite")
+            .with_consumer(CallCounter::default())
+            .with_consumer(ErrorRate::default())
+            .with_mode_printer(ModePrinter::File(path.clone()))
+            .with_report_format(ReportFormat::Markdown)
+    };
+    assert!(registry().install());
+    // the first installed registry is kept
+    assert!(!registry().install());
+
+    GlobalRegistry::record(&stat);
+    GlobalRegistry::record(&stat);
+    // the failed calls are recorded as well
+    record_failed_call(Statistic::failed(
+        "registry_call",
+        &TestError::Custom("Smart contract panicked".to_owned()),
+    ));
+    GlobalRegistry::finish()?;
+    assert!(!GlobalRegistry::is_enabled());
+
+    let summary = std::fs::read_to_string(&path)?;
+    assert!(summary.starts_with("# suite ("));
+    assert!(summary.contains("| registry_call | 3 |"));
+    assert!(summary.contains("| registry_call | 2 | 1 |"));
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
@@ -0,0 +1,83 @@
+mod statistic_fixtures;
+
+use crate::statistic_fixtures::{outcome, storage_stat};
+use integration_tests_toolset::{
+    error::TestError,
+    statistic::{
+        call_counter::CallCounter,
+        error_rate::ErrorRate,
+        mode_printer::ModePrinter,
+        statistic_consumer::{Statistic, StatisticConsumer},
+        statistic_group_ext::StatisticGroupExt,
+        statistic_trace::{read_trace, replay_trace, TraceWriter},
+    },
+    tx_result::{CallResult, OutcomeDetails, TxResultDetails},
+};
+use workspaces::{types::CryptoHash, AccountId};
+
+// Tests of the statistic traces, which don't require the sandbox
+
+#[test]
+fn test_statistic_trace() -> anyhow::Result<()> {
+    let path = std::env::temp_dir().join(format!("trace_{}.jsonl", std::process::id()));
+    let contract_id: AccountId = "contract.test.near".parse().unwrap();
+    let outcome = |gas_burnt| OutcomeDetails {
+        logs: vec!["log".to_owned()],
+        receipt_ids: vec![CryptoHash::default()],
+        method_name: Some("call_no_param_ret_u64".to_owned()),
+        ..outcome(&contract_id, gas_burnt)
+    };
+    let call = Statistic {
+        storage_deltas: [(contract_id.clone(), 100)].into_iter().collect(),
+        details: TxResultDetails::Call(CallResult {
+            gas: 3_000_000_000_000,
+            transaction_outcome: outcome(1_000_000_000_000),
+            receipt_failures: vec![],
+            receipt_outcomes: vec![outcome(2_000_000_000_000)],
+        }),
+        block_height: Some(42),
+        reached_block_height: Some(43),
+        tx_hash: Some(CryptoHash::default()),
+        contract_id: Some(contract_id.clone()),
+        caller: Some(contract_id.clone()),
+        args: Some("{}".to_owned()),
+        deposit: Some(u128::MAX),
+        retries: 1,
+        started_at: Some(std::time::SystemTime::now()),
+        finished_at: Some(std::time::SystemTime::now()),
+        ..storage_stat("call_no_param_ret_u64", 100)
+    };
+    let failure = Statistic::failed("view_account_id", &TestError::Custom("error".to_owned()))
+        .with_call_info(&contract_id, None, "{}".to_owned(), None);
+
+    let mut trace_writer = TraceWriter::new(&path, ModePrinter::default())?;
+    trace_writer.consume_statistic(&failure);
+    // the trace is restarted after the cleaning
+    trace_writer.clean_statistic();
+    vec![call.clone(), failure.clone()].populate_statistic(&mut [&mut trace_writer]);
+    trace_writer.flush()?;
+    assert_eq!(trace_writer.records(), 2);
+    assert_eq!(std::fs::read_to_string(&path)?.lines().count(), 2);
+
+    assert_eq!(read_trace(&path)?, vec![call.clone(), failure]);
+
+    let mut call_counter = CallCounter::default();
+    let mut error_rate = ErrorRate::default();
+    let mut consumers: [&mut dyn StatisticConsumer; 2] = [&mut call_counter, &mut error_rate];
+    assert_eq!(replay_trace(&path, &mut consumers)?, 2);
+    assert_eq!(call_counter.func_count.len(), 2);
+    assert_eq!(error_rate.errors.len(), 1);
+
+    let json = serde_json::to_value(&call)?;
+    assert_eq!(json["deposit"], u128::MAX.to_string());
+    assert_eq!(serde_json::from_value::<Statistic>(json)?, call);
+    // the statistic could be serialized with the non-self-describing formats as well
+    let bytes = bincode::serialize(&call)?;
+    assert_eq!(bincode::deserialize::<Statistic>(&bytes)?, call);
+
+    std::fs::write(&path, "{}\n")?;
+    assert!(read_trace(&path).is_err());
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
@@ -1,9 +1,17 @@
 mod contract_initializer;
 
+use integration_tests_toolset::{
+    statistic::{
+        gas_breakdown::GasBreakdown, receipt_usage::ReceiptUsage, report::strip_ansi,
+        statistic_printer::StatisticPrinter,
+    },
+    tx_result::TxResultDetails,
+};
 use maplit::hashmap;
 use scenario_toolset::{
     context_initialize::initialize_context,
     utils::{
+        balance_tracker::BalanceTracker,
         maker_id,
         token_info::{eth, usdc},
         TestAccount,
@@ -73,3 +81,190 @@ async fn test_ft_transfer_usage() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+// Test for fungible token balances tracking
+#[tokio::test]
+async fn test_balance_tracker_usage() -> anyhow::Result<()> {
+    let token_infos = [eth(), usdc()];
+    let (_, contract_template, _, tokens, [maker_account]) = initialize_context(
+        &token_infos,
+        &[TestAccount {
+            account_id: maker_id(),
+            mint_amount: hashmap! {
+                eth().to_string() => eth().parse("15")?
+            },
+        }],
+        &Initializer {},
+    )
+    .await?;
+
+    let contract_id = contract_template.contract.id().clone();
+    let mut tracker = BalanceTracker::new(
+        &token_infos,
+        &tokens,
+        &[maker_account.id().clone(), contract_id.clone()],
+    )?;
+    tracker.snapshot().await?;
+
+    let [eth_contract, _] = &tokens;
+    eth_contract
+        .ft_transfer(
+            contract_id.clone(),
+            eth().parse("1.5")?.into(),
+            None,
+            &maker_account,
+            1u128,
+        )
+        .await?;
+
+    // USDC balances are expected to stay unchanged
+    tracker
+        .assert_deltas(&[
+            (maker_account.id(), &eth(), "-1.5"),
+            (&contract_id, &eth(), "1.5"),
+        ])
+        .await?;
+
+    // mismatches are reported with the table
+    let err = tracker
+        .assert_deltas(&[(maker_account.id(), &eth(), "-1.5")])
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("Balance deltas mismatch"));
+
+    // deltas are reported in human units along with the raw amounts
+    tracker.measure_deltas().await?;
+    let report = strip_ansi(&tracker.make_report());
+    assert!(report.contains("-1.5 ETH") && report.contains("-1500000000000000000"));
+    tracker.print_statistic()?;
+
+    Ok(())
+}
+
+// Test for receipts fan-out statistic of the cross-contract calls
+#[tokio::test]
+async fn test_receipt_usage() -> anyhow::Result<()> {
+    let (_, contract_template, _, [eth, _usdc], [maker_account]) = initialize_context(
+        &[eth(), usdc()],
+        &[TestAccount {
+            account_id: maker_id(),
+            mint_amount: hashmap! {
+                eth().to_string() => eth().parse("15")?
+            },
+        }],
+        &Initializer {},
+    )
+    .await?;
+
+    let mut receipt_usage = ReceiptUsage::default();
+    let contract_id = contract_template.contract.id().clone();
+
+    eth.ft_transfer(contract_id.clone(), 10.into(), None, &maker_account, 1u128)
+        .await?
+        .populate_statistic(&mut [&mut receipt_usage]);
+
+    // ft_transfer_call -> ft_on_transfer -> ft_resolve_transfer chain touches both contracts
+    eth.ft_transfer_call(
+        contract_id,
+        10.into(),
+        None,
+        "Get my money!".to_owned(),
+        &maker_account,
+        1u128,
+    )
+    .await?
+    .populate_statistic(&mut [&mut receipt_usage]);
+
+    let depth = |func: &str| {
+        receipt_usage
+            .func_depth
+            .statistic(&func.to_owned())
+            .unwrap()
+            .max
+    };
+    let contracts = |func: &str| {
+        receipt_usage
+            .func_contracts
+            .statistic(&func.to_owned())
+            .unwrap()
+            .max
+    };
+    assert!(depth("ft_transfer_call") >= 3);
+    assert!(depth("ft_transfer_call") > depth("ft_transfer"));
+    assert!(contracts("ft_transfer_call") >= 2);
+
+    receipt_usage.print_statistic()?;
+
+    Ok(())
+}
+
+// Test for gas breakdown by the receipt executors and methods of the cross-contract calls
+#[tokio::test]
+async fn test_gas_breakdown() -> anyhow::Result<()> {
+    let (worker, contract_template, _, [eth, _usdc], [maker_account]) = initialize_context(
+        &[eth(), usdc()],
+        &[TestAccount {
+            account_id: maker_id(),
+            mint_amount: hashmap! {
+                eth().to_string() => eth().parse("15")?
+            },
+        }],
+        &Initializer {},
+    )
+    .await?;
+
+    let mut gas_breakdown = GasBreakdown::default();
+    let contract_id = contract_template.contract.id().clone();
+
+    let res = eth
+        .ft_transfer_call(
+            contract_id.clone(),
+            10.into(),
+            None,
+            "Get my money!".to_owned(),
+            &maker_account,
+            1u128,
+        )
+        .await?
+        .measure_receipt_methods(&worker)
+        .await?
+        .populate_statistic(&mut [&mut gas_breakdown]);
+
+    // gas of the token contract and the receiver contract is reported separately
+    let token_share = gas_breakdown.contract_share("ft_transfer_call", eth.contract.id());
+    let receiver_share = gas_breakdown.contract_share("ft_transfer_call", &contract_id);
+    let signer_share = gas_breakdown.contract_share("ft_transfer_call", maker_account.id());
+    assert!(token_share > 0. && receiver_share > 0.);
+    assert!((token_share + receiver_share + signer_share - 100.).abs() < 1e-6);
+
+    // gas of all receipts is summed up to the total gas of the call
+    let total_gas: u64 = gas_breakdown
+        .receipt_gas
+        .statistics()
+        .iter()
+        .map(|(_, stat)| stat.max)
+        .sum();
+    let TxResultDetails::Call(call_result) = &res.details else {
+        panic!("Unexpected view result of the call");
+    };
+    assert_eq!(total_gas, call_result.gas);
+
+    // gas is attributed to the methods of the cross-contract flow
+    let methods: Vec<_> = gas_breakdown
+        .receipt_gas
+        .statistics()
+        .into_iter()
+        .filter_map(|((_, _, account_id, method), _)| Some((account_id.clone(), method.clone()?)))
+        .collect();
+    for (account_id, method) in [
+        (eth.contract.id(), "ft_transfer_call"),
+        (&contract_id, "ft_on_transfer"),
+        (eth.contract.id(), "ft_resolve_transfer"),
+    ] {
+        assert!(methods.contains(&(account_id.clone(), method.to_owned())));
+    }
+
+    gas_breakdown.print_statistic()?;
+
+    Ok(())
+}